@@ -0,0 +1,50 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Advances the rotation angle by a constant angular speed over a time   *
+ *      step.                                                                 *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Function for incrementing the angle and updating its sine and cosine.     */
+use crate::rotate_by::rotate_by;
+
+/*  Range reduction used for the rotation angle.                              */
+use crate::set_rotation_angle::reduce_angle;
+
+/*  Function for advancing the rotation by the time elapsed since the last    *
+ *  frame. The speed of the animation is then independent of the frame rate.  */
+pub fn advance_rotation(dt_seconds: f32, radians_per_second: f32) {
+
+    /*  The angle swept out during the time step.                             */
+    let delta: f32 = dt_seconds * radians_per_second;
+
+    /*  A NaN or infinite step, from a bad time stamp for example, would make *
+     *  the angle NaN for the rest of the animation. Skip the frame.          */
+    if !delta.is_finite() {
+        return;
+    }
+
+    /*  When a browser tab is in the background, requestAnimationFrame is     *
+     *  paused and dt can be very large. Reduce the step first so that adding *
+     *  it to the current angle does not lose precision. rotate_by wraps the  *
+     *  sum back into [-pi, pi] and updates the cached sine and cosine.       */
+    rotate_by(reduce_angle(delta));
+}
+/*  End of advance_rotation.                                                  */
@@ -0,0 +1,97 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Rotates the mesh by the orientation quaternion.                       *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Orientation of the mesh, stored as the unit quaternion (w, x, y, z).      */
+use crate::ORIENTATION;
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  Number of points the mesh buffer currently has room for.                  */
+use crate::buffer_capacity::point_capacity;
+
+/*  Converting the points to z up and back, see set_up_axis.                  */
+use crate::up_axis::{is_y_up, from_up_axis, to_up_axis};
+
+/*  Function for rotating the mesh by the stored orientation. The quaternion  *
+ *  is about the axes of the surface with z up, even when y is up.            */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn apply_orientation(ptr: *mut f32, n_pts: u32) {
+
+    /*  Avoiding going beyond the bounds of the array that was allocated.     *
+     *  The points are at most what the mesh buffer has room for.             */
+    if (n_pts as usize) > point_capacity() {
+        return;
+    }
+
+    /*  Convert the pointer into a slice.                                     */
+    let n_elements: usize = 3 * (n_pts as usize);
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, n_elements) };
+
+    /*  Get the orientation. rotate_orientation keeps this a unit quaternion. */
+    let [w, x, y, z]: [f32; 4] = *lock(&ORIENTATION);
+
+    /*  The rotation matrix for the unit quaternion (w, x, y, z). Compute the *
+     *  nine entries once, before the loop.                                   */
+    let m00: f32 = 1.0 - 2.0 * (y * y + z * z);
+    let m01: f32 = 2.0 * (x * y - w * z);
+    let m02: f32 = 2.0 * (x * z + w * y);
+
+    let m10: f32 = 2.0 * (x * y + w * z);
+    let m11: f32 = 1.0 - 2.0 * (x * x + z * z);
+    let m12: f32 = 2.0 * (y * z - w * x);
+
+    let m20: f32 = 2.0 * (x * z - w * y);
+    let m21: f32 = 2.0 * (y * z + w * x);
+    let m22: f32 = 1.0 - 2.0 * (x * x + y * y);
+
+    /*  Whether the points are stored with y up, see set_up_axis.             */
+    let y_up: bool = is_y_up();
+
+    /*  Loop through each point in the mesh.                                  */
+    for index in 0..n_pts {
+
+        /*  A vertex has three values, the x, y, and z coordinates. The index *
+         *  for the x value of the point is 3 times the current index.        */
+        let x_index: usize = (3 * index) as usize;
+
+        /*  The y and z indices are immediately after the x index.            */
+        let y_index: usize = x_index + 1;
+        let z_index: usize = x_index + 2;
+
+        /*  Use the rotation matrix. Get the initial values, with z up.       */
+        let point: [f32; 3] = [arr[x_index], arr[y_index], arr[z_index]];
+        let [px, py, pz]: [f32; 3] = from_up_axis(point, y_up);
+
+        /*  Apply the rotation matrix and convert back to the chosen axis.    */
+        let rotated: [f32; 3] = [
+            m00 * px + m01 * py + m02 * pz,
+            m10 * px + m11 * py + m12 * pz,
+            m20 * px + m21 * py + m22 * pz
+        ];
+
+        arr[x_index..x_index + 3].copy_from_slice(&to_up_axis(rotated, y_up));
+    }
+}
+/*  End of apply_orientation.                                                 */
@@ -0,0 +1,93 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Returns the dimensions the static buffers were allocated for, and     *
+ *      allocates a buffer on first use.                                      *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  The current width and height of the static buffers.                       */
+use crate::BUFFER_DIMENSIONS;
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  Status for a mesh that is too wide or too tall.                           */
+use crate::dimension_status::dimension_status;
+
+/*  Number of elements in the index array for the given dimensions.           */
+use crate::index_count::index_count;
+
+/*  Number of elements in the triangle index array for given dimensions.      */
+use crate::triangle_index_count::triangle_index_count;
+
+/*  Number of elements in the periodic index array for given dimensions.      */
+use crate::periodic_index_count::periodic_index_count;
+
+/*  Function for getting the largest width and height the static buffers can  *
+ *  hold. This is MAX_WIDTH by MAX_HEIGHT unless init_buffers was called with *
+ *  something smaller.                                                        */
+pub fn buffer_capacity() -> (u32, u32) {
+    let dimensions: [u32; 2] = *lock(&BUFFER_DIMENSIONS);
+    return (dimensions[0], dimensions[1]);
+}
+
+/*  Function for checking nx_pts and ny_pts against the current capacity of   *
+ *  the buffers. Every generator that writes to one of the static buffers     *
+ *  checks its dimensions with this, rather than against MAX_WIDTH and        *
+ *  MAX_HEIGHT, since init_buffers may have made the buffers smaller.         */
+pub fn capacity_status(nx_pts: u32, ny_pts: u32) -> i32 {
+    let (width, height): (u32, u32) = buffer_capacity();
+    return dimension_status(nx_pts, ny_pts, width, height);
+}
+
+/*  Function for getting the number of points the mesh buffer has room for.   *
+ *  This is also the number of points in the normal and color buffers.        */
+pub fn point_capacity() -> usize {
+    let (width, height): (u32, u32) = buffer_capacity();
+    return (width as usize) * (height as usize);
+}
+
+/*  Function for getting the number of elements in the index buffer.          */
+pub fn index_capacity() -> usize {
+    let (width, height): (u32, u32) = buffer_capacity();
+    return index_count(width, height) as usize;
+}
+
+/*  Function for getting the number of elements in the triangle index buffer. */
+pub fn triangle_index_capacity() -> usize {
+    let (width, height): (u32, u32) = buffer_capacity();
+    return triangle_index_count(width, height) as usize;
+}
+
+/*  Function for getting the number of elements in the periodic index buffer. */
+pub fn periodic_index_capacity() -> usize {
+    let (width, height): (u32, u32) = buffer_capacity();
+    return periodic_index_count(width, height) as usize;
+}
+
+/*  Function for making sure a static buffer has at least len elements. The   *
+ *  buffers start out empty, so a module that never calls init_buffers still  *
+ *  gets full sized buffers the first time they are used.                     */
+pub fn reserve_buffer<T: Clone + Default>(buffer: &mut Vec<T>, len: usize) {
+    if buffer.len() < len {
+        buffer.resize(len, T::default());
+    }
+}
+/*  End of buffer_capacity.                                                   */
@@ -0,0 +1,152 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the mesh and wireframe in the static buffers and returns     *
+ *      where they are.                                                       *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Status codes returned to JavaScript.                                      */
+use crate::{STATUS_OK, STATUS_TOO_LARGE};
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  The buffers for the mesh and indices, and which mesh buffer is in front.  */
+use crate::{INDEX_BUFFER, MESH_BUFFER, MESH_BUFFER_BACK, MESH_BUFFERS_SWAPPED};
+
+/*  Used for reading the flag set by swap_mesh_buffers.                       */
+use std::sync::atomic::Ordering;
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  The current capacity of the buffers, and allocation on first use.         */
+use crate::buffer_capacity::{buffer_capacity, reserve_buffer};
+
+/*  Functions for computing the vertices and the wireframe.                   */
+use crate::generate_indices::generate_indices;
+use crate::generate_mesh::generate_mesh;
+
+/*  Number of elements in the vertex and index arrays for given dimensions.   */
+use crate::index_count::index_count;
+use crate::vertex_float_count::vertex_float_count;
+
+/*  Needed for handing the struct to JavaScript as a class.                   */
+use wasm_bindgen::prelude::*;
+
+/*  The addresses and lengths of the two arrays made by build_surface. The    *
+ *  lengths are in elements, f32's for the mesh and u32's for the indices,    *
+ *  and are zero if status is not STATUS_OK.                                  */
+#[wasm_bindgen]
+pub struct SurfaceHandle {
+    status: i32,
+    mesh_ptr: usize,
+    mesh_len: u32,
+    index_ptr: usize,
+    index_len: u32,
+}
+
+#[wasm_bindgen]
+impl SurfaceHandle {
+
+    /*  One of the status codes in lib.rs.                                    */
+    #[wasm_bindgen(getter)]
+    pub fn status(&self) -> i32 {
+        return self.status;
+    }
+
+    /*  Address of the vertex array, the front mesh buffer.                   */
+    #[wasm_bindgen(getter, js_name = "meshPtr")]
+    pub fn mesh_ptr(&self) -> usize {
+        return self.mesh_ptr;
+    }
+
+    /*  Number of f32's in the vertex array.                                  */
+    #[wasm_bindgen(getter, js_name = "meshLen")]
+    pub fn mesh_len(&self) -> u32 {
+        return self.mesh_len;
+    }
+
+    /*  Address of the index array.                                           */
+    #[wasm_bindgen(getter, js_name = "indexPtr")]
+    pub fn index_ptr(&self) -> usize {
+        return self.index_ptr;
+    }
+
+    /*  Number of u32's in the index array.                                   */
+    #[wasm_bindgen(getter, js_name = "indexLen")]
+    pub fn index_len(&self) -> u32 {
+        return self.index_len;
+    }
+}
+
+/*  Function for computing the mesh and the wireframe in one call. This       *
+ *  replaces calling getMeshBuffer, getIndexBuffer, generateMesh, and         *
+ *  generateIndices separately when setting up the scene.                     */
+pub fn build_surface(nx_pts: u32, ny_pts: u32) -> SurfaceHandle {
+
+    /*  The handle returned if anything goes wrong.                           */
+    let mut handle: SurfaceHandle = SurfaceHandle {
+        status: STATUS_TOO_LARGE,
+        mesh_ptr: 0,
+        mesh_len: 0,
+        index_ptr: 0,
+        index_len: 0,
+    };
+
+    /*  generate_mesh checks the input sizes against the current capacity as  *
+     *  well, but check before the buffers are locked and allocated.          */
+    handle.status = capacity_status(nx_pts, ny_pts);
+
+    if handle.status != STATUS_OK {
+        return handle;
+    }
+
+    /*  The dimensions the buffers are allocated for.                         */
+    let (width, height): (u32, u32) = buffer_capacity();
+
+    /*  Compute the vertices in whichever buffer is currently in front.       */
+    let mut mesh = if MESH_BUFFERS_SWAPPED.load(Ordering::Acquire) {
+        lock(&MESH_BUFFER_BACK)
+    } else {
+        lock(&MESH_BUFFER)
+    };
+
+    reserve_buffer(&mut mesh, vertex_float_count(width, height) as usize);
+    handle.status = generate_mesh(mesh.as_mut_ptr(), nx_pts, ny_pts);
+
+    if handle.status != STATUS_OK {
+        return handle;
+    }
+
+    /*  Same for the wireframe. The status is the same as for the mesh, since *
+     *  both check the same dimensions. Reserving may move the buffer, so the *
+     *  indices are always written and any cached wireframe is forgotten.     */
+    let mut indices = lock(&INDEX_BUFFER);
+    reserve_buffer(&mut indices, index_count(width, height) as usize);
+    generate_indices(indices.as_mut_ptr(), nx_pts, ny_pts);
+
+    handle.mesh_ptr = mesh.as_ptr() as usize;
+    handle.mesh_len = vertex_float_count(nx_pts, ny_pts);
+    handle.index_ptr = indices.as_ptr() as usize;
+    handle.index_len = index_count(nx_pts, ny_pts);
+    return handle;
+}
+/*  End of build_surface.                                                     */
@@ -0,0 +1,34 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the length of a buffer with overflow checking.               *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Function for computing the product a * b * c, the number of elements in a *
+ *  buffer with a * b entries of c values each. This is the length of the     *
+ *  slice made from a pointer passed in from JavaScript, so it is computed in *
+ *  usize and None is returned if the product does not fit, rather than       *
+ *  silently wrapping around to something small.                              */
+pub fn checked_len(a: u32, b: u32, c: u32) -> Option<usize> {
+    let ab: usize = (a as usize).checked_mul(b as usize)?;
+    return ab.checked_mul(c as usize);
+}
+/*  End of checked_len.                                                       */
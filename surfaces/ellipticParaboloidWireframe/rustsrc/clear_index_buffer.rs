@@ -0,0 +1,44 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Sets every element of the static index buffer to zero.                *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Buffer for the indices.                                                   */
+use crate::INDEX_BUFFER;
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  The zeroed buffer no longer holds the cached wireframe.                   */
+use crate::invalidate_index_cache::invalidate_index_cache;
+
+/*  Function for zeroing out the index buffer.                                */
+pub fn clear_index_buffer() {
+
+    /*  Lock the buffer so that we may safely write to it.                    */
+    let mut buffer = lock(&INDEX_BUFFER);
+
+    /*  Overwrite any stale indices left over from a previous, larger         *
+     *  wireframe.                                                            */
+    buffer.fill(0);
+    invalidate_index_cache();
+}
+/*  End of clear_index_buffer.                                                */
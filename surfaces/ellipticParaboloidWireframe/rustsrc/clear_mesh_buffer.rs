@@ -0,0 +1,48 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Sets every element of the static mesh buffer to zero.                 *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  The two buffers for the mesh, and which of the two is in front.           */
+use crate::{MESH_BUFFER, MESH_BUFFER_BACK, MESH_BUFFERS_SWAPPED};
+
+/*  Used for reading the flag set by swap_mesh_buffers.                       */
+use std::sync::atomic::Ordering;
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  Function for zeroing out the front mesh buffer, the one returned by       *
+ *  get_mesh_buffer.                                                          */
+pub fn clear_mesh_buffer() {
+
+    /*  Lock the buffer so that we may safely write to it. The front buffer   *
+     *  is MESH_BUFFER unless the buffers have been swapped.                  */
+    let mut buffer = if MESH_BUFFERS_SWAPPED.load(Ordering::Acquire) {
+        lock(&MESH_BUFFER_BACK)
+    } else {
+        lock(&MESH_BUFFER)
+    };
+
+    /*  Overwrite any stale vertices left over from a previous, larger mesh.  */
+    buffer.fill(0.0);
+}
+/*  End of clear_mesh_buffer.                                                 */
@@ -0,0 +1,81 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Removes the points of the mesh that lie on the negative side of a     *
+ *      plane.                                                                *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Number of points the mesh buffer currently has room for.                  */
+use crate::buffer_capacity::point_capacity;
+
+/*  Function for clipping the mesh against a plane. Points on the negative    *
+ *  side, where nx x + ny y + nz z + d < 0, are replaced with NaN's. Lines    *
+ *  and triangles with a NaN corner are not drawn by the GPU, so the index    *
+ *  buffers can be left alone. The number of points that are kept is          *
+ *  returned.                                                                 */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn clip_mesh(ptr: *mut f32, n_pts: u32,
+                 nx: f32, ny: f32, nz: f32, d: f32) -> u32 {
+
+    /*  Avoiding going beyond the bounds of the array that was allocated.     *
+     *  The points are at most what the mesh buffer has room for.             */
+    if (n_pts as usize) > point_capacity() {
+        return 0;
+    }
+
+    /*  Convert the pointer into a slice.                                     */
+    let n_elements: usize = 3 * (n_pts as usize);
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, n_elements) };
+
+    /*  The number of points on the non-negative side of the plane.           */
+    let mut n_kept: u32 = 0;
+
+    /*  Loop through each point in the mesh.                                  */
+    for index in 0..n_pts {
+
+        /*  A vertex has three values, the x, y, and z coordinates. The index *
+         *  for the x value of the point is 3 times the current index.        */
+        let x_index: usize = (3 * index) as usize;
+
+        /*  The y and z indices are immediately after the x index.            */
+        let y_index: usize = x_index + 1;
+        let z_index: usize = x_index + 2;
+
+        /*  Which side of the plane the point is on. Points that were already *
+         *  clipped give NaN, and the comparison below is false for NaN, so   *
+         *  clipping twice does not count them.                               */
+        let x: f32 = arr[x_index];
+        let y: f32 = arr[y_index];
+        let z: f32 = arr[z_index];
+        let side: f32 = nx * x + ny * y + nz * z + d;
+
+        if side >= 0.0 {
+            n_kept += 1;
+        } else {
+            arr[x_index] = f32::NAN;
+            arr[y_index] = f32::NAN;
+            arr[z_index] = f32::NAN;
+        }
+    }
+
+    return n_kept;
+}
+/*  End of clip_mesh.                                                         */
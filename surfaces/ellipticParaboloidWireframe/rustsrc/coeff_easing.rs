@@ -0,0 +1,91 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      State for easing the coefficients of the paraboloid towards new       *
+ *      values.                                                               *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  The coefficients a and b of z = a x^2 + b y^2 drawn by generate_mesh.     */
+const DEFAULT_COEFFS: [f32; 2] = [1.0, 2.0];
+
+/*  Progress of a transition from one pair of coefficients to another. The    *
+ *  times are in whatever units the caller passes to tick_coeffs, seconds for *
+ *  requestAnimationFrame timestamps divided by 1000.                         */
+pub struct CoeffEasing {
+
+    /*  The coefficients (a, b) at the current time.                          */
+    pub current: [f32; 2],
+
+    /*  The coefficients when the transition started, and where it ends.      */
+    pub start: [f32; 2],
+    pub target: [f32; 2],
+
+    /*  Time since the transition started, and how long it lasts.             */
+    pub elapsed: f32,
+    pub duration: f32,
+}
+
+impl CoeffEasing {
+
+    /*  Starts at rest on the coefficients of the elliptic paraboloid.        */
+    pub const fn new() -> Self {
+        return Self {
+            current: DEFAULT_COEFFS,
+            start: DEFAULT_COEFFS,
+            target: DEFAULT_COEFFS,
+            elapsed: 0.0,
+            duration: 0.0,
+        };
+    }
+
+    /*  Recomputes the current coefficients from the elapsed time. The        *
+     *  smoothstep curve s = t^2 (3 - 2t) starts and stops with zero speed,   *
+     *  so the surface does not jerk at either end of the transition.         */
+    pub fn update(&mut self) {
+
+        /*  A transition with no duration jumps straight to the target.       */
+        if self.duration <= 0.0 {
+            self.current = self.target;
+            return;
+        }
+
+        /*  Fraction of the transition completed, clamped to [0, 1].          */
+        let t: f32 = (self.elapsed / self.duration).clamp(0.0, 1.0);
+        let s: f32 = t * t * (3.0 - 2.0 * t);
+
+        for n in 0..2 {
+            let delta: f32 = self.target[n] - self.start[n];
+            self.current[n] = self.start[n] + s * delta;
+        }
+    }
+
+    /*  True while the coefficients are still moving towards the target.      */
+    pub fn is_active(&self) -> bool {
+        return self.elapsed < self.duration;
+    }
+}
+
+/*  The default is the same as new, at rest on the elliptic paraboloid.       */
+impl Default for CoeffEasing {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+/*  End of CoeffEasing.                                                       */
@@ -0,0 +1,45 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Checks the dimensions of a mesh against the largest allowed width and *
+ *      height.                                                               *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Status codes returned to JavaScript.                                      */
+use crate::{STATUS_OK, STATUS_TOO_TALL, STATUS_TOO_WIDE};
+
+/*  Function for checking nx_pts and ny_pts against the largest allowed width *
+ *  and height. A single status for both would not say which of the two was   *
+ *  too big, which matters when they come from separate sliders. Returns      *
+ *  STATUS_TOO_WIDE if the width is too big, STATUS_TOO_TALL if only the      *
+ *  height is, and STATUS_OK otherwise.                                       */
+pub fn dimension_status(nx_pts: u32, ny_pts: u32,
+                        max_w: u32, max_h: u32) -> i32 {
+    if nx_pts > max_w {
+        return STATUS_TOO_WIDE;
+    }
+
+    if ny_pts > max_h {
+        return STATUS_TOO_TALL;
+    }
+
+    return STATUS_OK;
+}
+/*  End of dimension_status.                                                  */
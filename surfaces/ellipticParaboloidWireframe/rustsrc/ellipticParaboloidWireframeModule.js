@@ -4,11 +4,48 @@ import init, {
     getMeshBuffer,
     getIndexBuffer,
     rotateMesh,
-    setRotationAngle
+    setRotationAngle,
+    vertexStride
 } from './pkg/paraboloid.js';
 
 const wasm = await init();
 
+/*  Status codes returned by generateMesh and generateIndices, see lib.rs.    */
+const STATUS_OK = 0;
+const STATUS_TOO_WIDE = -4;
+const STATUS_TOO_TALL = -5;
+
+/******************************************************************************
+ *  Function:                                                                 *
+ *      checkStatus                                                           *
+ *  Purpose:                                                                  *
+ *      Throws an error naming the dimension at fault if one of the           *
+ *      generators rejected its input.                                        *
+ *  Arguments:                                                                *
+ *      name (string):                                                        *
+ *          The name of the generator, used in the error message.             *
+ *      status (number):                                                      *
+ *          The status code the generator returned.                           *
+ *  Output:                                                                   *
+ *      None.                                                                 *
+ ******************************************************************************/
+function checkStatus(name, status) {
+    if (status === STATUS_OK) {
+        return;
+    }
+
+    if (status === STATUS_TOO_WIDE) {
+        throw new Error(`${name}: width is larger than the buffers allow.`);
+    }
+
+    if (status === STATUS_TOO_TALL) {
+        throw new Error(`${name}: height is larger than the buffers allow.`);
+    }
+
+    throw new Error(`${name}: failed with status ${status}.`);
+}
+/*  End of checkStatus.                                                       */
+
 /******************************************************************************
  *  Function:                                                                 *
  *      animate                                                               *
@@ -43,8 +80,11 @@ function animate(renderer, scene, camera, surface, arraySize) {
  ******************************************************************************/
 function setupGeometry(three, width, height) {
 
+    /*  The number of floats per vertex is read from the module rather than   *
+     *  hardcoded, so the two can not get out of sync.                        */
+    const stride = vertexStride();
     const numberOfPoints = width * height;
-    const bufferSize = 3 * numberOfPoints;
+    const bufferSize = stride * numberOfPoints;
     const indexSize = 2 * (2 * numberOfPoints - width - height);
 
     /*  three.js has parametric function tools, but this renders the object   *
@@ -64,12 +104,16 @@ function setupGeometry(three, width, height) {
      *  need a variable for the buffer attributes as well.                    */
     let geometryAttributes, indexAttribute;
 
-    generateMesh(mesh.byteOffset, width, height);
-    generateIndices(indices.byteOffset, width, height);
+    /*  Oversize input is reported per axis, so a slider range that is too    *
+     *  large says which of the two dimensions needs to be smaller.           */
+    checkStatus("generateMesh", generateMesh(mesh.byteOffset, width, height));
+    checkStatus(
+        "generateIndices", generateIndices(indices.byteOffset, width, height)
+    );
 
     /*  We can now create the buffer attributes. The data is 3D, hence the    *
-     *  itemSize parameter is 3.                                              */
-    geometryAttributes = new three.BufferAttribute(mesh, 3);
+     *  itemSize parameter is the vertex stride, which is 3.                  */
+    geometryAttributes = new three.BufferAttribute(mesh, stride);
     indexAttribute = new three.BufferAttribute(indices, 1);
 
     /*  Add the vertices and index array to the mesh.                         */
@@ -0,0 +1,86 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Exports the triangulated mesh as a Wavefront OBJ file.                *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Functions for computing the vertices and triangles of the surface.        */
+use crate::generate_mesh::generate_mesh;
+use crate::generate_triangle_indices::generate_triangle_indices;
+
+/*  Function for rotating the mesh by the current rotation angle.             */
+use crate::rotate_mesh::rotate_mesh;
+
+/*  Number of elements in the vertex array for the given dimensions.          */
+use crate::vertex_float_count::vertex_float_count;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Function for creating the contents of an OBJ file for the surface.        */
+pub fn export_obj(nx_pts: u32, ny_pts: u32) -> String {
+
+    /*  The file is built up line by line.                                    */
+    let mut obj: String = String::new();
+
+    /*  The same checks as the generators. There is nothing to export if the  *
+     *  mesh can not be generated, so return an empty file.                   */
+    if capacity_status(nx_pts, ny_pts) != STATUS_OK {
+        return obj;
+    }
+
+    /*  A grid with a single row or column has no triangles.                  */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return obj;
+    }
+
+    /*  The number of points, and the number of indices for the triangles.    */
+    let n_pts: u32 = nx_pts * ny_pts;
+    let n_floats: usize = vertex_float_count(nx_pts, ny_pts) as usize;
+    let n_indices: usize = (6 * (nx_pts - 1) * (ny_pts - 1)) as usize;
+
+    /*  Buffers for the mesh, computed the same way as the ones shown on the  *
+     *  screen. The exported mesh includes the current rotation, so the file  *
+     *  matches what the user is looking at.                                  */
+    let mut mesh: Vec<f32> = vec![0.0; n_floats];
+    let mut triangles: Vec<u32> = vec![0; n_indices];
+
+    generate_mesh(mesh.as_mut_ptr(), nx_pts, ny_pts);
+    rotate_mesh(mesh.as_mut_ptr(), n_pts);
+    generate_triangle_indices(triangles.as_mut_ptr(), nx_pts, ny_pts);
+
+    /*  Each vertex is a line of the form "v x y z".                          */
+    for point in mesh.chunks_exact(3) {
+        obj.push_str(&format!("v {} {} {}\n", point[0], point[1], point[2]));
+    }
+
+    /*  Each triangle is a line of the form "f i j k". OBJ indices start at   *
+     *  one, not zero, so shift every index by one.                           */
+    for face in triangles.chunks_exact(3) {
+        let (i, j, k): (u32, u32, u32) = (face[0], face[1], face[2]);
+        obj.push_str(&format!("f {} {} {}\n", i + 1, j + 1, k + 1));
+    }
+
+    return obj;
+}
+/*  End of export_obj.                                                        */
@@ -0,0 +1,125 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Exports the triangulated mesh as a binary STL file.                   *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Functions for computing the vertices, normals, and triangles.             */
+use crate::generate_mesh::generate_mesh;
+use crate::generate_normals::generate_normals;
+use crate::generate_triangle_indices::generate_triangle_indices;
+
+/*  Function for rotating the mesh by the current rotation angle.             */
+use crate::rotate_mesh::rotate_mesh;
+
+/*  Number of elements in the vertex array for the given dimensions.          */
+use crate::vertex_float_count::vertex_float_count;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Function for creating the contents of a binary STL file for the surface.  */
+pub fn export_stl(nx_pts: u32, ny_pts: u32) -> Vec<u8> {
+
+    /*  The same checks as the generators. There is nothing to export if the  *
+     *  mesh can not be generated, so return an empty file.                   */
+    if capacity_status(nx_pts, ny_pts) != STATUS_OK {
+        return Vec::new();
+    }
+
+    /*  A grid with a single row or column has no triangles.                  */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return Vec::new();
+    }
+
+    /*  The number of points, and the number of indices for the triangles.    */
+    let n_pts: u32 = nx_pts * ny_pts;
+    let n_floats: usize = vertex_float_count(nx_pts, ny_pts) as usize;
+    let n_triangles: u32 = 2 * (nx_pts - 1) * (ny_pts - 1);
+
+    /*  Buffers for the mesh. As with export_obj, the current rotation is     *
+     *  included. The normals are rotated by the same matrix as the points.   */
+    let mut mesh: Vec<f32> = vec![0.0; n_floats];
+    let mut normals: Vec<f32> = vec![0.0; n_floats];
+    let mut triangles: Vec<u32> = vec![0; (3 * n_triangles) as usize];
+
+    generate_mesh(mesh.as_mut_ptr(), nx_pts, ny_pts);
+    generate_normals(normals.as_mut_ptr(), nx_pts, ny_pts);
+    rotate_mesh(mesh.as_mut_ptr(), n_pts);
+    rotate_mesh(normals.as_mut_ptr(), n_pts);
+    generate_triangle_indices(triangles.as_mut_ptr(), nx_pts, ny_pts);
+
+    /*  A binary STL file is an 80 byte header, the number of triangles as a  *
+     *  32-bit integer, and then 50 bytes for each triangle. All values are   *
+     *  little-endian.                                                        */
+    let size: usize = 84 + 50 * (n_triangles as usize);
+    let mut stl: Vec<u8> = Vec::with_capacity(size);
+
+    /*  The header is ignored by most software, but it must not begin with    *
+     *  "solid", otherwise the file may be mistaken for an ASCII STL file.    */
+    let mut header: [u8; 80] = [0; 80];
+    let title: &[u8] = b"Elliptic paraboloid, z = x^2 + 2y^2";
+    header[..title.len()].copy_from_slice(title);
+    stl.extend_from_slice(&header);
+    stl.extend_from_slice(&n_triangles.to_le_bytes());
+
+    /*  Loop through the triangles, writing the normal and then the corners.  */
+    for face in triangles.chunks_exact(3) {
+
+        /*  The analytic normals are given at the vertices. The facet normal  *
+         *  is the average of the normals at the three corners, rescaled to   *
+         *  be a unit vector.                                                 */
+        let mut normal: [f32; 3] = [0.0; 3];
+
+        for corner in face {
+            let index: usize = (3 * corner) as usize;
+            normal[0] += normals[index];
+            normal[1] += normals[index + 1];
+            normal[2] += normals[index + 2];
+        }
+
+        /*  The normals all point upwards before rotating, so the sum is      *
+         *  never the zero vector.                                            */
+        let norm_sq: f32 = normal.iter().map(|n| n * n).sum();
+        let rcpr_norm: f32 = 1.0 / norm_sq.sqrt();
+
+        for value in normal {
+            stl.extend_from_slice(&(value * rcpr_norm).to_le_bytes());
+        }
+
+        /*  The three corners of the triangle, in counter-clockwise order.    */
+        for corner in face {
+            let index: usize = (3 * corner) as usize;
+
+            for value in &mesh[index..index + 3] {
+                stl.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+
+        /*  The "attribute byte count" is unused and must be zero.            */
+        stl.extend_from_slice(&0_u16.to_le_bytes());
+    }
+
+    return stl;
+}
+/*  End of export_stl.                                                        */
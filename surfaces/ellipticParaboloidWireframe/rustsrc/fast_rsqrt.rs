@@ -0,0 +1,75 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes 1 / sqrt(x), with a faster approximation if the fast-math    *
+ *      feature is enabled.                                                   *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  The magic constant for the initial guess. Reading the bits of a positive  *
+ *  f32 as an integer gives roughly 2^23 (log2(x) + 127). Halving and         *
+ *  negating this approximates log2(1 / sqrt(x)), and the constant restores   *
+ *  the exponent bias, with a small correction that minimizes the error.      */
+#[cfg(feature = "fast-math")]
+const RSQRT_MAGIC: u32 = 0x5F37_59DF;
+
+/*  Function for computing 1 / sqrt(x) for x > 0, used to normalize vectors.  *
+ *  This is the bit trick from Quake III followed by one step of Newton's     *
+ *  method, which brings the relative error below 0.18%. That is invisible in *
+ *  the shading, and avoids both a square root and a division. The input must *
+ *  be positive and finite, which holds for the squared lengths of the normal *
+ *  vectors since they are always at least 1 or strictly positive.            */
+#[cfg(feature = "fast-math")]
+#[inline(always)]
+pub fn fast_rsqrt(x: f32) -> f32 {
+
+    /*  Initial guess from the bits of x.                                     */
+    let guess: f32 = f32::from_bits(RSQRT_MAGIC - (x.to_bits() >> 1));
+
+    /*  One Newton step for f(y) = 1 / y^2 - x, y -> y (3 - x y^2) / 2.       */
+    return guess * (1.5 - 0.5 * x * guess * guess);
+}
+
+/*  Without the fast-math feature this is the exact computation.              */
+#[cfg(not(feature = "fast-math"))]
+#[inline(always)]
+pub fn fast_rsqrt(x: f32) -> f32 {
+    return 1.0 / x.sqrt();
+}
+/*  End of fast_rsqrt.                                                        */
+
+#[cfg(test)]
+mod tests {
+    use super::fast_rsqrt;
+
+    /*  The relative error is below 0.2% over many orders of magnitude. With  *
+     *  the fast-math feature off this checks the exact computation instead.  */
+    #[test]
+    fn relative_error_is_small() {
+        for exponent in -20..20 {
+            for step in 0..100 {
+                let mantissa: f32 = 1.0 + (step as f32) * 0.01;
+                let x: f32 = mantissa * 2.0_f32.powi(exponent);
+                let exact: f64 = 1.0 / (x as f64).sqrt();
+                let error: f64 = ((fast_rsqrt(x) as f64) - exact).abs() / exact;
+                assert!(error < 0.002);
+            }
+        }
+    }
+}
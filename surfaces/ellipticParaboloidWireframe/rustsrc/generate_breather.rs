@@ -0,0 +1,130 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the locations of the points in the mesh for the breather     *
+ *      surface.                                                              *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  The mesh covers [-U, U] x [-V, V] in the (u, v) plane.                    */
+pub use crate::{BREATHER_U_RANGE, BREATHER_V_RANGE};
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Functions for converting the points for y up, see set_up_axis.            */
+use crate::up_axis::{is_y_up, convert_up_axis};
+
+/*  Function for generating the mesh for the breather, a surface of constant  *
+ *  negative curvature, like the pseudosphere, that pulses along its axis.    *
+ *  With w = sqrt(1 - aa^2) and d = aa ((w cosh(aa u))^2 + (aa sin(w v))^2),  *
+ *  the parametrization is:                                                   *
+ *                                                                            *
+ *      x = -u + 2 (1 - aa^2) cosh(aa u) sinh(aa u) / d                       *
+ *      y = 2 w cosh(aa u) (-w cos(v) cos(w v) - sin(v) sin(w v)) / d         *
+ *      z = 2 w cosh(aa u) (-w sin(v) cos(w v) + cos(v) sin(w v)) / d         *
+ *                                                                            *
+ *  The parameter aa must be in the open interval (0, 1), and 0.4 gives the   *
+ *  usual picture. Nothing is written for any other value.                    */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_breather(ptr: *mut f32, n_u: u32, n_v: u32, aa: f32) {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (n_u < 2) || (n_v < 2) {
+        return;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(n_u, n_v) != STATUS_OK {
+        return;
+    }
+
+    /*  At aa = 0 the denominator is zero, and for aa >= 1 the square root is *
+     *  zero or imaginary. This also rejects NaN.                             */
+    if aa.is_nan() || (aa <= 0.0) || (aa >= 1.0) {
+        return;
+    }
+
+    /*  The size of the array passed to us is given by the number of points   *
+     *  in the mesh, which is simply n_u times n_v. Each point is 3 f32's.    */
+    let len: usize = (3 * n_u * n_v) as usize;
+
+    /*  Get a slice for the data.                                             */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Step sizes for the two parameters. Both intervals are symmetric.      */
+    let du: f32 = 2.0 * BREATHER_U_RANGE / ((n_u - 1) as f32);
+    let dv: f32 = 2.0 * BREATHER_V_RANGE / ((n_v - 1) as f32);
+
+    /*  Constants that only depend on aa.                                     */
+    let w: f32 = (1.0 - aa * aa).sqrt();
+    let wsq: f32 = w * w;
+
+    /*  Whether to convert the points for y up, see set_up_axis.              */
+    let y_up: bool = is_y_up();
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Row-major with u as the horizontal axis, index = v * n_u + u. The     *
+     *  wireframe is given by generate_indices.                               */
+    for v_index in 0..n_v {
+
+        /*  Convert the index to the v parameter.                             */
+        let v: f32 = -BREATHER_V_RANGE + (v_index as f32) * dv;
+        let (sin_v, cos_v): (f32, f32) = v.sin_cos();
+        let (sin_wv, cos_wv): (f32, f32) = (w * v).sin_cos();
+
+        for u_index in 0..n_u {
+
+            /*  Convert the index to the u parameter.                         */
+            let u: f32 = -BREATHER_U_RANGE + (u_index as f32) * du;
+
+            /*  cosh(aa u) is at most cosh(14), about 6 x 10^5, since aa < 1  *
+             *  and |u| <= 14. Its square is well within the range of an f32, *
+             *  so none of the terms below overflow.                          */
+            let cosh_au: f32 = (aa * u).cosh();
+            let sinh_au: f32 = (aa * u).sinh();
+            let a_sin_wv: f32 = aa * sin_wv;
+
+            /*  The denominator is at least aa w^2, which is positive.        */
+            let cosh_sq: f32 = cosh_au * cosh_au;
+            let denom: f32 = aa * (wsq * cosh_sq + a_sin_wv * a_sin_wv);
+            let factor: f32 = 2.0 * w * cosh_au / denom;
+
+            /*  Add this point to our vertex array.                           */
+            arr[index] = -u + 2.0 * wsq * cosh_au * sinh_au / denom;
+            arr[index + 1] = factor * (-w * cos_v * cos_wv - sin_v * sin_wv);
+            arr[index + 2] = factor * (-w * sin_v * cos_wv + cos_v * sin_wv);
+            convert_up_axis(arr, index, y_up);
+
+            /*  Move on to the next point in the mesh. A point needs 3 f32's. */
+            index += 3;
+        }
+        /*  End of u for-loop.                                                */
+    }
+    /*  End of v for-loop.                                                    */
+}
+/*  End of generate_breather.                                                 */
@@ -0,0 +1,128 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Generates the surface given by a sum of Gaussian bumps.               *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Physical width and height of the surface (projection onto the xy plane).  */
+pub use crate::{PARABOLOID_WIDTH, PARABOLOID_HEIGHT};
+
+/*  Left-most and bottom-most extremes of the surface (projected to xy plane).*/
+pub use crate::{PARABOLOID_X_START, PARABOLOID_Y_START};
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Functions for converting the points for y up, see set_up_axis.            */
+use crate::up_axis::{is_y_up, convert_up_axis};
+
+/*  Function for generating the mesh for a sum of Gaussians, like a kernel    *
+ *  density estimate. centers_ptr holds n_centers groups of four f32's, (cx,  *
+ *  cy, amp, sigma), and the height above (x, y) is the sum over them of      *
+ *                                                                            *
+ *          amp exp(-((x - cx)^2 + (y - cy)^2) / (2 sigma^2))                 *
+ *                                                                            *
+ *  A bump with sigma zero or negative, or any value that is not finite, is   *
+ *  skipped rather than turning the surface into NaN's. The grid is the same  *
+ *  as generate_mesh, so generate_indices gives the wireframe.                */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_bumps(ptr: *mut f32, nx_pts: u32, ny_pts: u32,
+                      centers_ptr: *const f32, n_centers: u32) {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(nx_pts, ny_pts) != STATUS_OK {
+        return;
+    }
+
+    /*  The size of the array passed to us is given by the number of points   *
+     *  in the mesh, which is simply the width times the height.              */
+    let len: usize = (3 * nx_pts * ny_pts) as usize;
+
+    /*  Get slices for the data and for the bumps, four f32's per bump.       */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+    let centers = unsafe {
+        std::slice::from_raw_parts(centers_ptr, 4 * (n_centers as usize))
+    };
+
+    /*  Keep the valid bumps, with the factor -1 / (2 sigma^2) in place of    *
+     *  sigma, so the inner loop only multiplies.                             */
+    let bumps: Vec<[f32; 4]> = centers
+        .chunks_exact(4)
+        .filter(|c| c.iter().all(|v| v.is_finite()) && (c[3] > 0.0))
+        .map(|c| [c[0], c[1], c[2], -0.5 / (c[3] * c[3])])
+        .collect();
+
+    /*  Step sizes in the horizontal and vertical axes.                       */
+    let dx: f32 = PARABOLOID_WIDTH / ((nx_pts - 1) as f32);
+    let dy: f32 = PARABOLOID_HEIGHT / ((ny_pts - 1) as f32);
+
+    /*  Whether to convert the points for y up, see set_up_axis.              */
+    let y_up: bool = is_y_up();
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Loop through the vertical axis. As with generate_mesh, the array is   *
+     *  indexed in row-major fashion, index = y * width + x.                  */
+    for y_index in 0..ny_pts {
+
+        /*  Convert pixel index to y coordinate.                              */
+        let y_pt: f32 = PARABOLOID_Y_START + (y_index as f32) * dy;
+
+        /*  Loop through the horizontal component of the object.              */
+        for x_index in 0..nx_pts {
+
+            /*  Convert pixel index to x coordinate in the plane.             */
+            let x_pt: f32 = PARABOLOID_X_START + (x_index as f32) * dx;
+
+            /*  Add up the contributions of all of the bumps.                 */
+            let mut z_pt: f32 = 0.0;
+
+            for &[cx, cy, amp, scale] in &bumps {
+                let ex: f32 = x_pt - cx;
+                let ey: f32 = y_pt - cy;
+                z_pt += amp * (scale * (ex * ex + ey * ey)).exp();
+            }
+
+            /*  Add this point to our vertex array.                           */
+            arr[index] = x_pt;
+            arr[index + 1] = y_pt;
+            arr[index + 2] = z_pt;
+            convert_up_axis(arr, index, y_up);
+
+            /*  Move on to the next point in the mesh. A point needs 3 f32's. */
+            index += 3;
+        }
+        /*  End of horizontal for-loop.                                       */
+    }
+    /*  End of vertical for-loop.                                             */
+}
+/*  End of generate_bumps.                                                    */
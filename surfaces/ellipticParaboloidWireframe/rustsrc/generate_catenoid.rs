@@ -0,0 +1,138 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the locations of the points in the mesh for a catenoid.      *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Range of the height parameter for the catenoid.                           */
+pub use crate::{CATENOID_U_START, CATENOID_U_WIDTH};
+
+/*  The angular parameter runs over [0, 2 pi].                                */
+use std::f32::consts::PI;
+
+/*  Computes the length of the buffer, checking for overflow.                 */
+use crate::checked_len::checked_len;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Functions for converting the points for y up, see set_up_axis.            */
+use crate::up_axis::{is_y_up, convert_up_axis};
+
+/*  Function for generating the mesh for a catenoid by calculating vertices.  */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_catenoid(ptr: *mut f32, n_u: u32, n_v: u32, c: f32) {
+
+    /*  The height is divided by c, so it must be non-zero and finite.        *
+     *  Otherwise every point would be NaN.                                   */
+    if !c.is_finite() || (c == 0.0) {
+        return;
+    }
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (n_u < 2) || (n_v < 2) {
+        return;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(n_u, n_v) != STATUS_OK {
+        return;
+    }
+
+    /*  The size of the array passed to us is given by the number of points   *
+     *  in the mesh, which is simply n_u times n_v. Each point is 3 f32's.    *
+     *  The product is checked for overflow.                                  */
+    let len: usize = match checked_len(n_u, n_v, 3) {
+        Some(len) => len,
+        None => return,
+    };
+
+    /*  Get a slice for the data.                                             */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Step sizes for the height and angular parameters.                     */
+    let du: f32 = CATENOID_U_WIDTH / ((n_u - 1) as f32);
+    let dv: f32 = 2.0 * PI / ((n_v - 1) as f32);
+
+    /*  Whether to convert the points for y up, see set_up_axis.              */
+    let y_up: bool = is_y_up();
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Loop through the angular parameter. Since this is the outer for-loop, *
+     *  the array is indexed in row-major fashion with u as the horizontal    *
+     *  axis. That is, index = v * n_u + u. The wireframe is obtained from    *
+     *  generate_indices(ptr, n_u, n_v).                                      */
+    for v_index in 0..n_v {
+
+        /*  Convert the index to an angle.                                    */
+        let v: f32 = (v_index as f32) * dv;
+        let cos_v: f32 = v.cos();
+        let sin_v: f32 = v.sin();
+
+        /*  Loop through the height parameter.                                */
+        for u_index in 0..n_u {
+
+            /*  Convert the index to a height. The distance from the central  *
+             *  axis is given by the catenary c cosh(u / c).                  */
+            let u: f32 = CATENOID_U_START + (u_index as f32) * du;
+            let rho: f32 = c * (u / c).cosh();
+
+            /*  Add this point to our vertex array. The catenoid is given by  *
+             *  (c cosh(u / c) cos(v), c cosh(u / c) sin(v), u).              */
+            arr[index] = rho * cos_v;
+            arr[index + 1] = rho * sin_v;
+            arr[index + 2] = u;
+            convert_up_axis(arr, index, y_up);
+
+            /*  Move on to the next point in the mesh. A point needs 3 f32's. */
+            index += 3;
+        }
+        /*  End of u for-loop.                                                */
+    }
+    /*  End of v for-loop.                                                    */
+}
+/*  End of generate_catenoid.                                                 */
+
+#[cfg(test)]
+mod tests {
+    use super::generate_catenoid;
+    use crate::TEST_LOCK;
+
+    /*  A zero or infinite c would divide the height by nothing, or turn it   *
+     *  into zero. Either way the mesh is left as it was.                     */
+    #[test]
+    fn degenerate_c_leaves_the_mesh_intact() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let mut mesh: [f32; 12] = [7.0; 12];
+
+        generate_catenoid(mesh.as_mut_ptr(), 2, 2, 0.0);
+        generate_catenoid(mesh.as_mut_ptr(), 2, 2, f32::INFINITY);
+        generate_catenoid(mesh.as_mut_ptr(), 2, 2, f32::NAN);
+        assert!(mesh.iter().all(|&x| x == 7.0));
+    }
+}
@@ -0,0 +1,135 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes colors for the points in the mesh based on their height,     *
+ *      producing a blue to red heat map.                                     *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Physical width and height of the surface (projection onto the xy plane).  */
+pub use crate::{PARABOLOID_WIDTH, PARABOLOID_HEIGHT};
+
+/*  Left-most and bottom-most extremes of the surface (projected to xy plane).*/
+pub use crate::{PARABOLOID_X_START, PARABOLOID_Y_START};
+
+/*  Computes the length of the buffer, checking for overflow.                 */
+use crate::checked_len::checked_len;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Computes the minimum and maximum of t^2 for t in [start, start + length]. */
+fn square_range(start: f32, length: f32) -> (f32, f32) {
+
+    /*  The end of the interval.                                              */
+    let end: f32 = start + length;
+
+    /*  t^2 is maximized at one of the endpoints of the interval.             */
+    let max_val: f32 = (start * start).max(end * end);
+
+    /*  If the interval contains zero, the minimum is zero. Otherwise, the    *
+     *  minimum is also attained at one of the endpoints.                     */
+    if (start <= 0.0) && (end >= 0.0) {
+        return (0.0, max_val);
+    }
+
+    return ((start * start).min(end * end), max_val);
+}
+/*  End of square_range.                                                      */
+
+/*  Function for generating the colors for the surface at each vertex.        */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_colors(ptr: *mut f32, nx_pts: u32, ny_pts: u32) {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(nx_pts, ny_pts) != STATUS_OK {
+        return;
+    }
+
+    /*  There is one color for each point in the mesh, and each color needs   *
+     *  three f32's, the red, green, and blue components.                     *
+     *  The product is checked for overflow.                                  */
+    let len: usize = match checked_len(nx_pts, ny_pts, 3) {
+        Some(len) => len,
+        None => return,
+    };
+
+    /*  Get a slice for the data.                                             */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Step sizes in the horizontal and vertical axes.                       */
+    let dx: f32 = PARABOLOID_WIDTH / ((nx_pts - 1) as f32);
+    let dy: f32 = PARABOLOID_HEIGHT / ((ny_pts - 1) as f32);
+
+    /*  The heights are normalized by the smallest and largest values of z =  *
+     *  x^2 + 2y^2 over the entire domain, not just the sampled points, so    *
+     *  that the colors are the same regardless of the resolution.            */
+    let x_range = square_range(PARABOLOID_X_START, PARABOLOID_WIDTH);
+    let y_range = square_range(PARABOLOID_Y_START, PARABOLOID_HEIGHT);
+    let z_min: f32 = x_range.0 + 2.0 * y_range.0;
+    let z_max: f32 = x_range.1 + 2.0 * y_range.1;
+    let rcpr_z_range: f32 = 1.0 / (z_max - z_min);
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Loop through the points in the same row-major order as generate_mesh  *
+     *  so that the i^th color corresponds to the i^th vertex.                */
+    for y_index in 0..ny_pts {
+
+        /*  Convert pixel index to y coordinate.                              */
+        let y_pt: f32 = PARABOLOID_Y_START + (y_index as f32) * dy;
+
+        /*  Loop through the horizontal component of the object.              */
+        for x_index in 0..nx_pts {
+
+            /*  Convert pixel index to x coordinate in the plane.             */
+            let x_pt: f32 = PARABOLOID_X_START + (x_index as f32) * dx;
+
+            /*  Same formula as generate_mesh. The shift used there to center *
+             *  the surface cancels after normalizing, so we omit it here.    */
+            let z_pt: f32 = x_pt * x_pt + 2.0 * y_pt * y_pt;
+
+            /*  Normalize the height to the interval [0, 1].                  */
+            let t: f32 = (z_pt - z_min) * rcpr_z_range;
+
+            /*  Low points are blue, high points are red.                     */
+            arr[index] = t;
+            arr[index + 1] = 0.0;
+            arr[index + 2] = 1.0 - t;
+
+            /*  Move on to the next point in the mesh. A color needs 3 f32's. */
+            index += 3;
+        }
+        /*  End of horizontal for-loop.                                       */
+    }
+    /*  End of vertical for-loop.                                             */
+}
+/*  End of generate_colors.                                                   */
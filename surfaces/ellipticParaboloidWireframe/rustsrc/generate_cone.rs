@@ -0,0 +1,118 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the locations of the points in the mesh for a cone.          *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  The angle about the axis of the cone runs over [0, 2 pi].                 */
+use std::f32::consts::PI;
+
+/*  Computes the length of the buffer, checking for overflow.                 */
+use crate::checked_len::checked_len;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Functions for converting the points for y up, see set_up_axis.            */
+use crate::up_axis::{is_y_up, convert_up_axis};
+
+/*  Function for generating the mesh for a cone by calculating vertices.      */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_cone(ptr: *mut f32, n_theta: u32, n_height: u32,
+                     radius: f32, height: f32) {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (n_theta < 2) || (n_height < 2) {
+        return;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  The angle plays the role of the horizontal axis, and the height       *
+     *  parameter plays the role of the vertical one. Check the sizes.        */
+    if capacity_status(n_theta, n_height) != STATUS_OK {
+        return;
+    }
+
+    /*  The size of the array passed to us is given by the number of points   *
+     *  in the mesh, which is the number of angles times the number of        *
+     *  heights. Each point needs three f32's.                                *
+     *  The product is checked for overflow.                                  */
+    let len: usize = match checked_len(n_theta, n_height, 3) {
+        Some(len) => len,
+        None => return,
+    };
+
+    /*  Get a slice for the data.                                             */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Step sizes for the angle and the height parameter, which is in [0, 1].*/
+    let dtheta: f32 = 2.0 * PI / ((n_theta - 1) as f32);
+    let dt: f32 = 1.0 / ((n_height - 1) as f32);
+
+    /*  Whether to convert the points for y up, see set_up_axis.              */
+    let y_up: bool = is_y_up();
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Loop through the height parameter. Since this is the outer for-loop,  *
+     *  the array is indexed in row-major fashion with the angle as the       *
+     *  horizontal axis. That is, index = t * n_theta + theta. The wireframe  *
+     *  is obtained from generate_indices(ptr, n_theta, n_height).            *
+     *                                                                        *
+     *  Note, the row t = 1 collapses to a single point, the apex of the      *
+     *  cone. As with the poles of the sphere, we still write all n_theta     *
+     *  copies of this point so that the vertex array is completely filled    *
+     *  and the parameter grid remains rectangular. The wireframe will then   *
+     *  contain a few zero-length edges at the apex, which is harmless.       */
+    for t_index in 0..n_height {
+
+        /*  Convert the index to the height parameter. The radius of the      *
+         *  circular cross section shrinks linearly to zero as t goes to 1.   */
+        let t: f32 = (t_index as f32) * dt;
+        let rho: f32 = radius * (1.0 - t);
+        let z_pt: f32 = height * t;
+
+        /*  Loop through the angle about the axis of the cone.                */
+        for theta_index in 0..n_theta {
+
+            /*  Convert the index to an angle.                                */
+            let theta: f32 = (theta_index as f32) * dtheta;
+
+            /*  Add this point to our vertex array. The cone is given by (r   *
+             *  (1 - t) cos(theta), r (1 - t) sin(theta), h t).               */
+            arr[index] = rho * theta.cos();
+            arr[index + 1] = rho * theta.sin();
+            arr[index + 2] = z_pt;
+            convert_up_axis(arr, index, y_up);
+
+            /*  Move on to the next point in the mesh. A point needs 3 f32's. */
+            index += 3;
+        }
+        /*  End of angular for-loop.                                          */
+    }
+    /*  End of height for-loop.                                               */
+}
+/*  End of generate_cone.                                                     */
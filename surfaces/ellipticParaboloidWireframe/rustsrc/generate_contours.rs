@@ -0,0 +1,250 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the level sets of the surface using marching squares.        *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Physical width and height of the surface (projection onto the xy plane).  */
+pub use crate::{PARABOLOID_WIDTH, PARABOLOID_HEIGHT};
+
+/*  Left-most and bottom-most extremes of the surface (projected to xy plane).*/
+pub use crate::{PARABOLOID_X_START, PARABOLOID_Y_START};
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Computes the length of a slice, returning None if it does not fit.        */
+use crate::checked_len::checked_len;
+
+/*  Global for the shift in the z axis, see set_height_shift.                 */
+use crate::HEIGHT_SHIFT;
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  Functions for converting the points for y up, see set_up_axis.            */
+use crate::up_axis::{is_y_up, convert_up_axis};
+
+/*  Computes the height of the surface, the same formula as generate_mesh.    */
+fn height(x_pt: f32, y_pt: f32, height_shift: f32) -> f32 {
+    return x_pt * x_pt + 2.0 * y_pt * y_pt + height_shift;
+}
+/*  End of height.                                                            */
+
+/*  Finds where the level set crosses the edge from (xa, ya) to (xb, yb). The *
+ *  heights at the end points are za and zb. The point is found by linear     *
+ *  interpolation, and the result is None if there is no crossing.            */
+fn edge_crossing(xa: f32, ya: f32, za: f32,
+                 xb: f32, yb: f32, zb: f32, level: f32) -> Option<[f32; 2]> {
+
+    /*  The level set crosses the edge if one end is on or above the level    *
+     *  and the other is below it. In this case za and zb differ, so the      *
+     *  division below is safe.                                               */
+    if (za >= level) == (zb >= level) {
+        return None;
+    }
+
+    /*  Fraction of the way along the edge where the height equals the level. */
+    let t: f32 = (level - za) / (zb - za);
+    return Some([xa + t * (xb - xa), ya + t * (yb - ya)]);
+}
+/*  End of edge_crossing.                                                     */
+
+/*  Function for computing the contour lines of the surface at the given      *
+ *  heights. The output is a list of line segments, each given by two xyz     *
+ *  points, suitable for a LineSegments object. A square can contain at most  *
+ *  two segments per level, so len, the length of the array in floats, must   *
+ *  be at least 12 (w - 1) (h - 1) n, where w is the width, h is the height,  *
+ *  and n is the number of levels. The number of segments written is          *
+ *  returned, or zero if the input is invalid or the array is too short.      */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_contours(ptr: *mut f32, len: u32, nx_pts: u32, ny_pts: u32,
+                         levels: &[f32]) -> u32 {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return 0;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(nx_pts, ny_pts) != STATUS_OK {
+        return 0;
+    }
+
+    /*  The largest possible number of floats, as described above. The        *
+     *  number of squares fits in a u32 since the dimensions were checked.    */
+    let n_squares: u32 = (nx_pts - 1) * (ny_pts - 1);
+
+    let max_len: usize = match checked_len(n_squares, 12, 1) {
+        Some(n) => match n.checked_mul(levels.len()) {
+            Some(n) => n,
+            None => return 0,
+        },
+        None => return 0,
+    };
+
+    /*  Avoiding writing beyond the bounds of the array from the caller.      */
+    if max_len > (len as usize) {
+        return 0;
+    }
+
+    /*  Get a slice for the data.                                             */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, max_len) };
+
+    /*  Shift factor in the z axis, read once rather than for every point.    */
+    let height_shift: f32 = *lock(&HEIGHT_SHIFT);
+
+    /*  Step sizes in the horizontal and vertical axes.                       */
+    let dx: f32 = PARABOLOID_WIDTH / ((nx_pts - 1) as f32);
+    let dy: f32 = PARABOLOID_HEIGHT / ((ny_pts - 1) as f32);
+
+    /*  Whether to convert the points for y up, see set_up_axis.              */
+    let y_up: bool = is_y_up();
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  The number of segments found so far.                                  */
+    let mut n_segments: u32 = 0;
+
+    /*  Loop over the bottom left corners of the squares in the grid, in the  *
+     *  same order as generate_triangle_indices.                              */
+    for y_index in 0..(ny_pts - 1) {
+
+        /*  The bottom and top edges of the current row of squares.           */
+        let y0: f32 = PARABOLOID_Y_START + (y_index as f32) * dy;
+        let y1: f32 = y0 + dy;
+
+        for x_index in 0..(nx_pts - 1) {
+
+            /*  The left and right edges of the current square.               */
+            let x0: f32 = PARABOLOID_X_START + (x_index as f32) * dx;
+            let x1: f32 = x0 + dx;
+
+            /*  The heights at the four corners of the square.                */
+            let z00: f32 = height(x0, y0, height_shift);
+            let z01: f32 = height(x1, y0, height_shift);
+            let z10: f32 = height(x0, y1, height_shift);
+            let z11: f32 = height(x1, y1, height_shift);
+
+            for &level in levels {
+
+                /*  The crossings on the bottom, right, top, and left edges,  *
+                 *  in counter-clockwise order. Edges without a crossing are  *
+                 *  skipped, so only the first n_found entries are used.      */
+                let mut found: [[f32; 2]; 4] = [[0.0; 2]; 4];
+                let mut n_found: usize = 0;
+
+                let edges: [Option<[f32; 2]>; 4] = [
+                    edge_crossing(x0, y0, z00, x1, y0, z01, level),
+                    edge_crossing(x1, y0, z01, x1, y1, z11, level),
+                    edge_crossing(x1, y1, z11, x0, y1, z10, level),
+                    edge_crossing(x0, y1, z10, x0, y0, z00, level)
+                ];
+
+                for point in edges.into_iter().flatten() {
+                    found[n_found] = point;
+                    n_found += 1;
+                }
+
+                /*  Pairs of crossings, indexed as above, joined by a         *
+                 *  segment. A level set crosses a square zero, two, or four  *
+                 *  times.                                                    */
+                let pairs: &[(usize, usize)] = if n_found == 2 {
+                    &[(0, 1)]
+                }
+
+                /*  Four crossings is a saddle, and the corners alternate     *
+                 *  between above and below the level. Use the average of the *
+                 *  corners as the height at the center. If the center is on  *
+                 *  the same side as the bottom left corner, the segments cut *
+                 *  off the bottom right and top left corners. Otherwise they *
+                 *  cut off the other two corners.                            */
+                else if n_found == 4 {
+                    let center: f32 = 0.25 * (z00 + z01 + z10 + z11);
+
+                    if (center >= level) == (z00 >= level) {
+                        &[(0, 1), (2, 3)]
+                    } else {
+                        &[(3, 0), (1, 2)]
+                    }
+                }
+
+                /*  Otherwise the level set misses the square.                */
+                else {
+                    &[]
+                };
+
+                /*  Write each segment to the array.                          */
+                for &(start, end) in pairs {
+
+                    /*  Points on a level set all have the same height.       */
+                    arr[index] = found[start][0];
+                    arr[index + 1] = found[start][1];
+                    arr[index + 2] = level;
+                    convert_up_axis(arr, index, y_up);
+                    arr[index + 3] = found[end][0];
+                    arr[index + 4] = found[end][1];
+                    arr[index + 5] = level;
+                    convert_up_axis(arr, index + 3, y_up);
+
+                    /*  A segment needs 6 f32's.                              */
+                    index += 6;
+                    n_segments += 1;
+                }
+            }
+            /*  End of for-loop over the levels.                              */
+        }
+        /*  End of horizontal for-loop.                                       */
+    }
+    /*  End of vertical for-loop.                                             */
+
+    return n_segments;
+}
+/*  End of generate_contours.                                                 */
+
+#[cfg(test)]
+mod tests {
+    use super::generate_contours;
+    use crate::TEST_LOCK;
+
+    /*  A 3x3 grid has 4 squares, so one level needs room for 48 floats. An   *
+     *  array one float short is rejected without being written to.           */
+    #[test]
+    fn rejects_an_array_that_is_too_short() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let mut arr: [f32; 48] = [7.0; 48];
+        let levels: [f32; 1] = [0.0];
+
+        let short: u32 = generate_contours(arr.as_mut_ptr(), 47, 3, 3, &levels);
+        assert_eq!(short, 0);
+        assert!(arr.iter().all(|&x| x == 7.0));
+
+        let segments: u32 = generate_contours(arr.as_mut_ptr(), 48, 3, 3,
+                                              &levels);
+        assert!(segments <= 8);
+    }
+}
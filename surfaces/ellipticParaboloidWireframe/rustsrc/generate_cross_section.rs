@@ -0,0 +1,156 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the curve where a plane x = c or y = c cuts the mesh of the  *
+ *      paraboloid.                                                           *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Physical width and height of the surface (projection onto the xy plane).  */
+pub use crate::{PARABOLOID_WIDTH, PARABOLOID_HEIGHT};
+
+/*  Left-most and bottom-most extremes of the surface (projected to xy plane).*/
+pub use crate::{PARABOLOID_X_START, PARABOLOID_Y_START};
+
+/*  Shift in the z axis, set by set_height_shift.                             */
+use crate::HEIGHT_SHIFT;
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  Conversion from z up to the axis chosen with set_up_axis.                 */
+use crate::up_axis::{is_y_up, to_up_axis};
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Values for plane_axis, slicing with x = c or with y = c.                  */
+pub const CROSS_SECTION_X: u32 = 0;
+pub const CROSS_SECTION_Y: u32 = 1;
+
+/*  Function for computing the cross section of the mesh from generate_mesh   *
+ *  by the plane x = plane_value, if plane_axis is CROSS_SECTION_X, or by y = *
+ *  plane_value, if it is CROSS_SECTION_Y. The plane falls between two        *
+ *  columns (or rows) of the grid, and on every line of the grid crossing it  *
+ *  the height is interpolated linearly between the two neighboring points.   *
+ *  This is where the plane cuts the wireframe that is drawn, rather than the *
+ *  exact parabola, so the slice sits on the rendered surface at any          *
+ *  resolution. The points are written to out_ptr in order of increasing y    *
+ *  (or x), three f32's each, forming a polyline for a THREE.Line. Returns    *
+ *  the number of points, ny_pts for x = c and nx_pts for y = c, or zero if   *
+ *  the plane misses the surface or the input is invalid.                     */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_cross_section(nx_pts: u32, ny_pts: u32, plane_axis: u32,
+                              plane_value: f32, out_ptr: *mut f32) -> u32 {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return 0;
+    }
+
+    /*  The output is one row or column of the mesh, so the same limits hold. */
+    if capacity_status(nx_pts, ny_pts) != STATUS_OK {
+        return 0;
+    }
+
+    /*  The slicing plane is perpendicular to one of the two grid axes. Call  *
+     *  that axis u and the other one v. The curve is parametrized by v.      */
+    let (n_u, n_v, u0, u_width, v0, v_width) = match plane_axis {
+        CROSS_SECTION_X => (nx_pts, ny_pts,
+                            PARABOLOID_X_START, PARABOLOID_WIDTH,
+                            PARABOLOID_Y_START, PARABOLOID_HEIGHT),
+        CROSS_SECTION_Y => (ny_pts, nx_pts,
+                            PARABOLOID_Y_START, PARABOLOID_HEIGHT,
+                            PARABOLOID_X_START, PARABOLOID_WIDTH),
+        _ => return 0,
+    };
+
+    /*  Position of the plane in units of the grid spacing. The plane misses  *
+     *  the surface unless this lies in [0, n_u - 1].                         */
+    let du: f64 = (u_width as f64) / ((n_u - 1) as f64);
+    let u_val: f64 = plane_value as f64;
+    let grid_pos: f64 = (u_val - (u0 as f64)) / du;
+    let grid_end: f64 = (n_u - 1) as f64;
+
+    if grid_pos.is_nan() || (grid_pos < 0.0) || (grid_pos > grid_end) {
+        return 0;
+    }
+
+    /*  The two grid lines on either side of the plane. On the last line the  *
+     *  lower one is used, with the plane at the far end of the interval.     */
+    let lower: u32 = (grid_pos.floor() as u32).min(n_u - 2);
+    let frac: f64 = grid_pos - (lower as f64);
+    let u_lo: f64 = (u0 as f64) + (lower as f64) * du;
+    let u_hi: f64 = u_lo + du;
+
+    /*  Get a slice for the data. Each point needs three f32's.               */
+    let len: usize = (3 * n_v) as usize;
+    let arr = unsafe { std::slice::from_raw_parts_mut(out_ptr, len) };
+
+    /*  Step size along the curve, and the settings used by generate_mesh.    */
+    let dv: f64 = (v_width as f64) / ((n_v - 1) as f64);
+    let height_shift: f64 = *lock(&HEIGHT_SHIFT) as f64;
+    let y_up: bool = is_y_up();
+
+    /*  Heights of the grid points, z = x^2 + 2y^2 shifted, as generate_mesh. */
+    let height = |u_pt: f64, v_pt: f64| -> f64 {
+        let (x_pt, y_pt) = if plane_axis == CROSS_SECTION_X {
+            (u_pt, v_pt)
+        } else {
+            (v_pt, u_pt)
+        };
+
+        return x_pt * x_pt + 2.0 * y_pt * y_pt + height_shift;
+    };
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    for v_index in 0..n_v {
+
+        /*  The grid line crossing the plane, and the height where it does.   */
+        let v_pt: f64 = (v0 as f64) + (v_index as f64) * dv;
+        let z_lo: f64 = height(u_lo, v_pt);
+        let z_hi: f64 = height(u_hi, v_pt);
+        let z_pt: f64 = z_lo + frac * (z_hi - z_lo);
+
+        let point: [f32; 3] = if plane_axis == CROSS_SECTION_X {
+            [u_val as f32, v_pt as f32, z_pt as f32]
+        } else {
+            [v_pt as f32, u_val as f32, z_pt as f32]
+        };
+
+        let [px, py, pz]: [f32; 3] = to_up_axis(point, y_up);
+        arr[index] = px;
+        arr[index + 1] = py;
+        arr[index + 2] = pz;
+
+        /*  Move on to the next point on the curve. A point needs 3 f32's.    */
+        index += 3;
+    }
+    /*  End of for-loop along the curve.                                      */
+
+    return n_v;
+}
+/*  End of generate_cross_section.                                            */
@@ -0,0 +1,100 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the Gaussian curvature of the surface at each point in the   *
+ *      mesh.                                                                 *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Physical width and height of the surface (projection onto the xy plane).  */
+pub use crate::{PARABOLOID_WIDTH, PARABOLOID_HEIGHT};
+
+/*  Left-most and bottom-most extremes of the surface (projected to xy plane).*/
+pub use crate::{PARABOLOID_X_START, PARABOLOID_Y_START};
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Function for computing the Gaussian curvature K at each vertex. Unlike    *
+ *  the vertex and normal arrays there is one f32 per point, which can be     *
+ *  mapped to a color to show where the surface bends the most.               */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_curvature(ptr: *mut f32, nx_pts: u32, ny_pts: u32) {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(nx_pts, ny_pts) != STATUS_OK {
+        return;
+    }
+
+    /*  There is a single scalar for each point in the mesh.                  */
+    let len: usize = (nx_pts * ny_pts) as usize;
+
+    /*  Get a slice for the data.                                             */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Step sizes in the horizontal and vertical axes.                       */
+    let dx: f32 = PARABOLOID_WIDTH / ((nx_pts - 1) as f32);
+    let dy: f32 = PARABOLOID_HEIGHT / ((ny_pts - 1) as f32);
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Loop through the points in the same row-major order as generate_mesh  *
+     *  so that the i^th value corresponds to the i^th vertex.                */
+    for y_index in 0..ny_pts {
+
+        /*  Convert pixel index to y coordinate.                              */
+        let y_pt: f32 = PARABOLOID_Y_START + (y_index as f32) * dy;
+
+        /*  Loop through the horizontal component of the object.              */
+        for x_index in 0..nx_pts {
+
+            /*  Convert pixel index to x coordinate in the plane.             */
+            let x_pt: f32 = PARABOLOID_X_START + (x_index as f32) * dx;
+
+            /*  For a graph z = f(x, y) the Gaussian curvature is given by    *
+             *  the determinant of the Hessian divided by (1 + f_x^2 +        *
+             *  f_y^2)^2. For z = x^2 + 2y^2 we have f_xx = 2, f_yy = 4, and  *
+             *  f_xy = 0, so the numerator is 8. K is largest at the vertex.  */
+            let fx: f32 = 2.0 * x_pt;
+            let fy: f32 = 4.0 * y_pt;
+            let denom: f32 = 1.0 + fx * fx + fy * fy;
+
+            /*  The denominator is at least 1, so this never divides by zero. */
+            arr[index] = 8.0 / (denom * denom);
+
+            /*  Move on to the next point in the mesh.                        */
+            index += 1;
+        }
+        /*  End of horizontal for-loop.                                       */
+    }
+    /*  End of vertical for-loop.                                             */
+}
+/*  End of generate_curvature.                                                */
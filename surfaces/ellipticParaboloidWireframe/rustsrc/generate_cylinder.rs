@@ -0,0 +1,121 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the locations of the points in the mesh for a cylinder,      *
+ *      optionally with caps.                                                 *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  The angle runs over [0, 2 pi).                                            */
+use std::f32::consts::PI;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks against the current capacity of the buffers.                       */
+use crate::buffer_capacity::{capacity_status, point_capacity};
+
+/*  Functions for converting the points for y up, see set_up_axis.            */
+use crate::up_axis::{is_y_up, convert_up_axis};
+
+/*  Function for generating the mesh for the cylinder of the given radius     *
+ *  whose axis is the segment from (0, 0, 0) to (0, 0, height). The point for *
+ *  (theta, t) is (r cos(theta), r sin(theta), h t), with theta the           *
+ *  horizontal axis and t in [0, 1] the vertical one. If capped, two more     *
+ *  points are added after the grid, the centers of the bottom and top disks, *
+ *  in that order. Use generate_indices_cylinder for the wireframe. Returns   *
+ *  the number of vertices written, or zero if the input is invalid.          */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_cylinder(ptr: *mut f32, n_theta: u32, n_height: u32,
+                         radius: f32, height: f32, capped: bool) -> u32 {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (n_theta < 2) || (n_height < 2) {
+        return 0;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  The angle plays the role of the horizontal axis. Check the sizes.     */
+    if capacity_status(n_theta, n_height) != STATUS_OK {
+        return 0;
+    }
+
+    /*  The grid, plus the two centers of the caps if requested.              */
+    let n_pts: u32 = n_theta * n_height + if capped { 2 } else { 0 };
+
+    /*  The caps need two points more than the grid, so a grid that fills the *
+     *  mesh buffer leaves no room for them. Check the total number of points.*/
+    if (n_pts as usize) > point_capacity() {
+        return 0;
+    }
+
+    /*  Get a slice for the data. Each point needs three f32's.               */
+    let len: usize = 3 * (n_pts as usize);
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  The seam is closed by the indices, so the angle stops one step short  *
+     *  of 2 pi. Otherwise the first and last columns would coincide.         */
+    let dtheta: f32 = 2.0 * PI / (n_theta as f32);
+    let dz: f32 = height / ((n_height - 1) as f32);
+
+    /*  Whether to convert the points for y up, see set_up_axis.              */
+    let y_up: bool = is_y_up();
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Row-major, same as the other surfaces, with index = t * width + theta.*/
+    for z_index in 0..n_height {
+
+        /*  The height of the current ring.                                   */
+        let z_pt: f32 = (z_index as f32) * dz;
+
+        for theta_index in 0..n_theta {
+
+            /*  Compute the point on the circle of the given radius.          */
+            let theta: f32 = (theta_index as f32) * dtheta;
+
+            arr[index] = radius * theta.cos();
+            arr[index + 1] = radius * theta.sin();
+            arr[index + 2] = z_pt;
+            convert_up_axis(arr, index, y_up);
+
+            /*  Move on to the next point in the mesh. A point needs 3 f32's. */
+            index += 3;
+        }
+        /*  End of theta for-loop.                                            */
+    }
+    /*  End of height for-loop.                                               */
+
+    /*  The centers of the bottom and top disks, on the axis.                 */
+    if capped {
+        arr[index] = 0.0;
+        arr[index + 1] = 0.0;
+        arr[index + 2] = 0.0;
+        arr[index + 3] = 0.0;
+        arr[index + 4] = 0.0;
+        arr[index + 5] = height;
+        convert_up_axis(arr, index + 3, y_up);
+    }
+
+    return n_pts;
+}
+/*  End of generate_cylinder.                                                 */
@@ -0,0 +1,110 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the locations of the points in the mesh for Dini's surface.  *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Range of the twisting angle u, and of the angle v from the axis.          */
+pub use crate::{DINI_U_WIDTH, DINI_V_END, DINI_V_START};
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Functions for converting the points for y up, see set_up_axis.            */
+use crate::up_axis::{is_y_up, convert_up_axis};
+
+/*  Function for generating the mesh for Dini's surface, a pseudosphere that  *
+ *  twists around its axis as it goes. It has constant negative curvature,    *
+ *  K = -1 / (a^2 + b^2). The parametrization is:                             *
+ *                                                                            *
+ *          x = a cos(u) sin(v)                                               *
+ *          y = a sin(u) sin(v)                                               *
+ *          z = a (cos(v) + ln(tan(v / 2))) + b u                             *
+ *                                                                            *
+ *  with u in [0, 4 pi], two full turns, and v in [0.1, 2]. At v = 0 the log  *
+ *  goes to minus infinity and the surface runs off along the axis, so v must *
+ *  stay away from zero, and past v = pi the tangent is negative. The lower   *
+ *  limit DINI_V_START sets how far down the axis the surface reaches.        */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_dini(ptr: *mut f32, n_u: u32, n_v: u32, a: f32, b: f32) {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (n_u < 2) || (n_v < 2) {
+        return;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(n_u, n_v) != STATUS_OK {
+        return;
+    }
+
+    /*  The size of the array passed to us is given by the number of points   *
+     *  in the mesh, which is simply n_u times n_v. Each point is 3 f32's.    */
+    let len: usize = (3 * n_u * n_v) as usize;
+
+    /*  Get a slice for the data.                                             */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Step sizes for the two parameters. The surface does not close up, so  *
+     *  both intervals include their endpoints.                               */
+    let du: f32 = DINI_U_WIDTH / ((n_u - 1) as f32);
+    let dv: f32 = (DINI_V_END - DINI_V_START) / ((n_v - 1) as f32);
+
+    /*  Whether to convert the points for y up, see set_up_axis.              */
+    let y_up: bool = is_y_up();
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Row-major with u as the horizontal axis, index = v * n_u + u. The     *
+     *  wireframe is given by generate_indices.                               */
+    for v_index in 0..n_v {
+
+        /*  Convert the index to an angle. Everything but b u depends on v.   */
+        let v: f32 = DINI_V_START + (v_index as f32) * dv;
+        let (sin_v, cos_v): (f32, f32) = v.sin_cos();
+        let z_v: f32 = a * (cos_v + (0.5 * v).tan().ln());
+
+        for u_index in 0..n_u {
+
+            /*  Convert the index to an angle around the axis.                */
+            let u: f32 = (u_index as f32) * du;
+            let (sin_u, cos_u): (f32, f32) = u.sin_cos();
+
+            /*  Add this point to our vertex array.                           */
+            arr[index] = a * cos_u * sin_v;
+            arr[index + 1] = a * sin_u * sin_v;
+            arr[index + 2] = z_v + b * u;
+            convert_up_axis(arr, index, y_up);
+
+            /*  Move on to the next point in the mesh. A point needs 3 f32's. */
+            index += 3;
+        }
+        /*  End of u for-loop.                                                */
+    }
+    /*  End of v for-loop.                                                    */
+}
+/*  End of generate_dini.                                                     */
@@ -0,0 +1,116 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes colors for the edges of the wireframe based on their lengths.*
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Functions for computing the vertices and the wireframe.                   */
+use crate::generate_indices::generate_indices;
+use crate::generate_mesh::generate_mesh;
+
+/*  Number of elements in the vertex and index arrays.                        */
+use crate::index_count::index_count;
+use crate::vertex_float_count::vertex_float_count;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Function for coloring each edge of the wireframe by its length. The edges *
+ *  are in the same order as generate_indices, and each edge gets two colors, *
+ *  one for each end, so the array needs 3 times index_count floats. Per-edge *
+ *  colors require the line segments to be drawn without an index buffer,     *
+ *  with the two ends of each edge stored separately. Short edges are blue    *
+ *  and long edges are red, relative to the shortest and longest edges.       */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_edge_colors(ptr: *mut f32, nx_pts: u32, ny_pts: u32) {
+
+    /*  A grid needs at least two points along each axis. Otherwise the       *
+     *  length of the array underflows, so return early.                      */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(nx_pts, ny_pts) != STATUS_OK {
+        return;
+    }
+
+    /*  The sizes of the mesh and wireframe arrays. Each edge is two indices, *
+     *  and each index gets a color of 3 f32's.                               */
+    let n_floats: usize = vertex_float_count(nx_pts, ny_pts) as usize;
+    let n_indices: usize = index_count(nx_pts, ny_pts) as usize;
+    let len: usize = 3 * n_indices;
+
+    /*  Get a slice for the data.                                             */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Compute the mesh and the wireframe, the same as the ones drawn.       */
+    let mut mesh: Vec<f32> = vec![0.0; n_floats];
+    let mut indices: Vec<u32> = vec![0; n_indices];
+
+    generate_mesh(mesh.as_mut_ptr(), nx_pts, ny_pts);
+    generate_indices(indices.as_mut_ptr(), nx_pts, ny_pts);
+
+    /*  The length of each edge in space.                                     */
+    let lengths: Vec<f32> = indices.chunks_exact(2).map(|edge| {
+        let p: usize = (3 * edge[0]) as usize;
+        let q: usize = (3 * edge[1]) as usize;
+
+        let dx: f32 = mesh[q] - mesh[p];
+        let dy: f32 = mesh[q + 1] - mesh[p + 1];
+        let dz: f32 = mesh[q + 2] - mesh[p + 2];
+
+        (dx * dx + dy * dy + dz * dz).sqrt()
+    }).collect();
+
+    /*  The shortest and longest edges, used to normalize the lengths.        */
+    let min_len: f32 = lengths.iter().copied().fold(f32::INFINITY, f32::min);
+    let max_len: f32 = lengths.iter().copied().fold(0.0, f32::max);
+
+    /*  If every edge has the same length, color them all blue.               */
+    let rcpr_range: f32 = if max_len > min_len {
+        1.0 / (max_len - min_len)
+    } else {
+        0.0
+    };
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    for length in lengths {
+
+        /*  Normalize the length to the interval [0, 1].                      */
+        let t: f32 = (length - min_len) * rcpr_range;
+
+        /*  Both ends of the edge get the same color, as in generate_colors.  */
+        for _ in 0..2 {
+            arr[index] = t;
+            arr[index + 1] = 0.0;
+            arr[index + 2] = 1.0 - t;
+            index += 3;
+        }
+    }
+}
+/*  End of generate_edge_colors.                                              */
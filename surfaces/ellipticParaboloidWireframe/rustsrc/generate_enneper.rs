@@ -0,0 +1,105 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the locations of the points in the mesh for Enneper's minimal*
+ *      surface.                                                              *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Functions for converting the points for y up, see set_up_axis.            */
+use crate::up_axis::{is_y_up, convert_up_axis};
+
+/*  Function for generating the mesh for Enneper's surface over the square    *
+ *  [-range, range] x [-range, range]. Like the catenoid and helicoid it is a *
+ *  minimal surface. The parametrization is:                                  *
+ *                                                                            *
+ *          x = u - u^3 / 3 + u v^2                                           *
+ *          y = v - v^3 / 3 + v u^2                                           *
+ *          z = u^2 - v^2                                                     *
+ *                                                                            *
+ *  The surface is embedded inside the disk u^2 + v^2 < 3. The corners of     *
+ *  the square leave this disk once range is larger than about 1.22, and the  *
+ *  surface then intersects itself. This is expected.                         */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_enneper(ptr: *mut f32, n_u: u32, n_v: u32, range: f32) {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (n_u < 2) || (n_v < 2) {
+        return;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(n_u, n_v) != STATUS_OK {
+        return;
+    }
+
+    /*  The size of the array passed to us is given by the number of points   *
+     *  in the mesh, which is simply n_u times n_v. Each point is 3 f32's.    */
+    let len: usize = (3 * n_u * n_v) as usize;
+
+    /*  Get a slice for the data.                                             */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Step sizes for the two parameters, both over [-range, range].         */
+    let du: f32 = 2.0 * range / ((n_u - 1) as f32);
+    let dv: f32 = 2.0 * range / ((n_v - 1) as f32);
+
+    /*  Whether to convert the points for y up, see set_up_axis.              */
+    let y_up: bool = is_y_up();
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Row-major with u as the horizontal axis, index = v * n_u + u. The     *
+     *  wireframe is given by generate_indices.                               */
+    for v_index in 0..n_v {
+
+        /*  Convert the index to the v parameter.                             */
+        let v: f32 = -range + (v_index as f32) * dv;
+        let vsq: f32 = v * v;
+
+        for u_index in 0..n_u {
+
+            /*  Convert the index to the u parameter.                         */
+            let u: f32 = -range + (u_index as f32) * du;
+            let usq: f32 = u * u;
+
+            /*  Add this point to our vertex array.                           */
+            arr[index] = u * (1.0 - usq / 3.0 + vsq);
+            arr[index + 1] = v * (1.0 - vsq / 3.0 + usq);
+            arr[index + 2] = usq - vsq;
+            convert_up_axis(arr, index, y_up);
+
+            /*  Move on to the next point in the mesh. A point needs 3 f32's. */
+            index += 3;
+        }
+        /*  End of u for-loop.                                                */
+    }
+    /*  End of v for-loop.                                                    */
+}
+/*  End of generate_enneper.                                                  */
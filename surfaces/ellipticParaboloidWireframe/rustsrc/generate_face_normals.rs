@@ -0,0 +1,165 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes one normal vector for each triangle in the mesh, for flat    *
+ *      shading.                                                              *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Physical width and height of the surface (projection onto the xy plane).  */
+pub use crate::{PARABOLOID_WIDTH, PARABOLOID_HEIGHT};
+
+/*  Left-most and bottom-most extremes of the surface (projected to xy plane).*/
+pub use crate::{PARABOLOID_X_START, PARABOLOID_Y_START};
+
+/*  Reciprocal square root, approximate with the fast-math feature.           */
+use crate::fast_rsqrt::fast_rsqrt;
+
+/*  Computes the length of the buffer, checking for overflow.                 */
+use crate::checked_len::checked_len;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Global for the shift in the z axis, see set_height_shift.                 */
+use crate::HEIGHT_SHIFT;
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Functions for converting the normals for y up, see set_up_axis.           */
+use crate::up_axis::{is_y_up, to_up_axis};
+
+/*  Computes the point on the surface above (x, y), as in generate_mesh.      */
+fn surface_point(x_pt: f32, y_pt: f32, height_shift: f32) -> [f32; 3] {
+    return [x_pt, y_pt, x_pt * x_pt + 2.0 * y_pt * y_pt + height_shift];
+}
+/*  End of surface_point.                                                     */
+
+/*  Computes the unit normal of the triangle with corners p0, p1, and p2.     */
+fn triangle_normal(p0: [f32; 3], p1: [f32; 3], p2: [f32; 3]) -> [f32; 3] {
+
+    /*  The two edges coming out of the first vertex.                         */
+    let ux: f32 = p1[0] - p0[0];
+    let uy: f32 = p1[1] - p0[1];
+    let uz: f32 = p1[2] - p0[2];
+
+    let vx: f32 = p2[0] - p0[0];
+    let vy: f32 = p2[1] - p0[1];
+    let vz: f32 = p2[2] - p0[2];
+
+    /*  The cross product of the edges is perpendicular to the triangle. The  *
+     *  corners are counter-clockwise when viewed from above, so it points    *
+     *  up.                                                                   */
+    let cx: f32 = uy * vz - uz * vy;
+    let cy: f32 = uz * vx - ux * vz;
+    let cz: f32 = ux * vy - uy * vx;
+
+    /*  The triangles come from a grid over the xy plane, so the projection   *
+     *  of each triangle has positive area and cz is never zero.              */
+    let rcpr_norm: f32 = fast_rsqrt(cx * cx + cy * cy + cz * cz);
+    return [cx * rcpr_norm, cy * rcpr_norm, cz * rcpr_norm];
+}
+/*  End of triangle_normal.                                                   */
+
+/*  Function for generating the normal of each triangle in the mesh. The i^th *
+ *  normal belongs to the i^th triangle of generate_triangle_indices, so the  *
+ *  array needs 6 (w - 1) (h - 1) floats, where w is the width and h is the   *
+ *  height. For flat shading in three.js, each triangle needs its own copy of *
+ *  its three vertices, so the geometry should be non-indexed and every       *
+ *  normal repeated for the three corners of its triangle.                    */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_face_normals(ptr: *mut f32, nx_pts: u32, ny_pts: u32) {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(nx_pts, ny_pts) != STATUS_OK {
+        return;
+    }
+
+    /*  There are two triangles per square and (w - 1) (h - 1) squares. Each  *
+     *  normal vector needs three f32's.                                      *
+     *  The product is checked for overflow.                                  */
+    let len: usize = match checked_len(nx_pts - 1, ny_pts - 1, 6) {
+        Some(len) => len,
+        None => return,
+    };
+
+    /*  Get a slice for the data.                                             */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Shift factor in the z axis, read once rather than for every point.    */
+    let height_shift: f32 = *lock(&HEIGHT_SHIFT);
+
+    /*  Step sizes in the horizontal and vertical axes.                       */
+    let dx: f32 = PARABOLOID_WIDTH / ((nx_pts - 1) as f32);
+    let dy: f32 = PARABOLOID_HEIGHT / ((ny_pts - 1) as f32);
+
+    /*  Whether to convert the points for y up, see set_up_axis.              */
+    let y_up: bool = is_y_up();
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Loop over the bottom left corners of the squares in the grid, in the  *
+     *  same order as generate_triangle_indices.                              */
+    for y_index in 0..(ny_pts - 1) {
+
+        /*  The bottom and top edges of the current row of squares.           */
+        let y0: f32 = PARABOLOID_Y_START + (y_index as f32) * dy;
+        let y1: f32 = y0 + dy;
+
+        for x_index in 0..(nx_pts - 1) {
+
+            /*  The left and right edges of the current square.               */
+            let x0: f32 = PARABOLOID_X_START + (x_index as f32) * dx;
+            let x1: f32 = x0 + dx;
+
+            /*  The four corners of the square.                               */
+            let p00: [f32; 3] = surface_point(x0, y0, height_shift);
+            let p01: [f32; 3] = surface_point(x1, y0, height_shift);
+            let p10: [f32; 3] = surface_point(x0, y1, height_shift);
+            let p11: [f32; 3] = surface_point(x1, y1, height_shift);
+
+            /*  The same two triangles as generate_triangle_indices, split    *
+             *  along the diagonal from the bottom left to the top right.     */
+            let n0: [f32; 3] = triangle_normal(p00, p01, p11);
+            let n1: [f32; 3] = triangle_normal(p00, p11, p10);
+
+            arr[index..index + 3].copy_from_slice(&to_up_axis(n0, y_up));
+            arr[index + 3..index + 6].copy_from_slice(&to_up_axis(n1, y_up));
+
+            /*  Move on to the next square. Two normals need 6 f32's.         */
+            index += 6;
+        }
+        /*  End of horizontal for-loop.                                       */
+    }
+    /*  End of vertical for-loop.                                             */
+}
+/*  End of generate_face_normals.                                             */
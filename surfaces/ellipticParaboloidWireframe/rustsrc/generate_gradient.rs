@@ -0,0 +1,150 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes line segments showing the gradient of the height at each     *
+ *      point in the mesh.                                                    *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Physical width and height of the surface (projection onto the xy plane).  */
+pub use crate::{PARABOLOID_WIDTH, PARABOLOID_HEIGHT};
+
+/*  Left-most and bottom-most extremes of the surface (projected to xy plane).*/
+pub use crate::{PARABOLOID_X_START, PARABOLOID_Y_START};
+
+/*  Computes the length of the buffer, checking for overflow.                 */
+use crate::checked_len::checked_len;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Global for the shift in the z axis, see set_height_shift.                 */
+use crate::HEIGHT_SHIFT;
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Functions for converting the points for y up, see set_up_axis.            */
+use crate::up_axis::{is_y_up, convert_up_axis};
+
+/*  Function for generating the gradient vector field on the surface. Each    *
+ *  point in the mesh gets a segment, given by two xyz points, starting on    *
+ *  the surface and pointing uphill. The array needs 6 w h floats, where w is *
+ *  the width and h is the height.                                            */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_gradient(ptr: *mut f32, nx_pts: u32, ny_pts: u32) {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(nx_pts, ny_pts) != STATUS_OK {
+        return;
+    }
+
+    /*  Each segment needs two points, and each point needs three f32's.      *
+     *  The product is checked for overflow.                                  */
+    let len: usize = match checked_len(nx_pts, ny_pts, 6) {
+        Some(len) => len,
+        None => return,
+    };
+
+    /*  Get a slice for the data.                                             */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Step sizes in the horizontal and vertical axes.                       */
+    let dx: f32 = PARABOLOID_WIDTH / ((nx_pts - 1) as f32);
+    let dy: f32 = PARABOLOID_HEIGHT / ((ny_pts - 1) as f32);
+
+    /*  Shift factor in the z axis, read once rather than for every point.    */
+    let height_shift: f32 = *lock(&HEIGHT_SHIFT);
+
+    /*  Coefficients of the surface z = ax^2 + by^2, as in generate_mesh.     */
+    const A: f32 = 1.0;
+    const B: f32 = 2.0;
+
+    /*  Every arrow is drawn with the same length, only the direction varies. */
+    const ARROW_LENGTH: f32 = 0.1;
+
+    /*  Whether to convert the points for y up, see set_up_axis.              */
+    let y_up: bool = is_y_up();
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Loop through the points in the same row-major order as generate_mesh  *
+     *  so that the i^th segment starts at the i^th vertex.                   */
+    for y_index in 0..ny_pts {
+
+        /*  Convert pixel index to y coordinate.                              */
+        let y_pt: f32 = PARABOLOID_Y_START + (y_index as f32) * dy;
+
+        /*  Loop through the horizontal component of the object.              */
+        for x_index in 0..nx_pts {
+
+            /*  Convert pixel index to x coordinate in the plane.             */
+            let x_pt: f32 = PARABOLOID_X_START + (x_index as f32) * dx;
+
+            /*  The point on the surface where the segment starts.            */
+            let z_pt: f32 = A * x_pt * x_pt + B * y_pt * y_pt + height_shift;
+
+            /*  The gradient of ax^2 + by^2 is (2ax, 2by).                    */
+            let grad_x: f32 = 2.0 * A * x_pt;
+            let grad_y: f32 = 2.0 * B * y_pt;
+
+            /*  Rescale the gradient to the arrow length. At a critical point *
+             *  the gradient is zero and has no direction, so the segment     *
+             *  collapses to a single point.                                  */
+            let norm: f32 = (grad_x * grad_x + grad_y * grad_y).sqrt();
+
+            let scale: f32 = if norm == 0.0 {
+                0.0
+            } else {
+                ARROW_LENGTH / norm
+            };
+
+            /*  The start of the segment, on the surface.                     */
+            arr[index] = x_pt;
+            arr[index + 1] = y_pt;
+            arr[index + 2] = z_pt;
+            convert_up_axis(arr, index, y_up);
+
+            /*  The end of the segment. The gradient lies in the xy plane.    */
+            arr[index + 3] = x_pt + scale * grad_x;
+            arr[index + 4] = y_pt + scale * grad_y;
+            arr[index + 5] = z_pt;
+            convert_up_axis(arr, index + 3, y_up);
+
+            /*  Move on to the next point in the mesh. A segment needs 6      *
+             *  f32's.                                                        */
+            index += 6;
+        }
+        /*  End of horizontal for-loop.                                       */
+    }
+    /*  End of vertical for-loop.                                             */
+}
+/*  End of generate_gradient.                                                 */
@@ -24,21 +24,53 @@
 /*  Function prototype and index array found here.                            */
 pub use crate::{MAX_HEIGHT, MAX_WIDTH};
 
-/*  Function for generating the wireframe for the animation.                  */
-pub fn generate_indices(ptr: *mut u32, nx_pts: u32, ny_pts: u32) {
+/*  Status codes returned to JavaScript, zero means success.                  */
+use crate::{STATUS_OK, STATUS_TOO_LARGE, STATUS_DEGENERATE};
+
+/*  Number of elements in the index array for the given dimensions.           */
+use crate::index_count::index_count;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Function for forgetting the wireframe cached by generateIndicesCached.    */
+use crate::invalidate_index_cache::invalidate_index_cache;
+
+/*  Function for writing the wireframe without touching the cache. This is    *
+ *  generate_indices for generate_indices_cached, which holds the lock on the *
+ *  cache while it writes and sets the cache itself afterwards.               */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn write_indices(ptr: *mut u32, nx_pts: u32, ny_pts: u32) -> i32 {
+
+    /*  A grid needs at least two points along each axis. Otherwise the       *
+     *  length of the array underflows, so return early.                      */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return STATUS_DEGENERATE;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big. This is done before the slice   *
+     *  is created, since a slice longer than the allocation is undefined     *
+     *  behavior even if the extra elements are never touched.                */
+    let status: i32 = capacity_status(nx_pts, ny_pts);
+
+    if status != STATUS_OK {
+        return status;
+    }
+
+    /*  The number of elements, 2 (2wh - w - h), is less than 4wh. The check  *
+     *  above keeps this small, but make sure with checked arithmetic that    *
+     *  the length can not wrap around, even if MAX_WIDTH or MAX_HEIGHT are   *
+     *  raised.                                                               */
+    if nx_pts.checked_mul(ny_pts).and_then(|n| n.checked_mul(4)).is_none() {
+        return STATUS_TOO_LARGE;
+    }
 
     /*  We are only passed the address of the data, we need to convert it     *
      *  into a slice. This requires the total number of elements in the array,*
      *  which is given in terms of the number number of points in the mesh.   */
-    let number_of_points: u32 = nx_pts * ny_pts;
-
-    /*  For each vertex, there are two edges comming out of it forming an "L" *
-     *  shape. This is true for all points except those along the top edge    *
-     *  and the right-most edge, where there is only one edge. Lastly, for    *
-     *  each edge there are two vertices, the starting vertex and the         *
-     *  terminal one, meaning there are 2 (2wh - w - h) elements in the index *
-     *  array, where w is the width and h is the height.                      */
-    let len = (2 * (2 * number_of_points - nx_pts - ny_pts)) as usize;
+    let len = index_count(nx_pts, ny_pts) as usize;
 
     /*  Convert the pointer into a slice.                                     */
     let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
@@ -46,12 +78,6 @@ pub fn generate_indices(ptr: *mut u32, nx_pts: u32, ny_pts: u32) {
     /*  Variable for indexing over the array being written to.                */
     let mut index: usize = 0;
 
-    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
-     *  Check if the input sizes are too big.                                 */
-    if (nx_pts > MAX_WIDTH) || (ny_pts > MAX_HEIGHT) {
-        return;
-    }
-
     /*  We need to create the lines now. We do this by creating ordered       *
      *  pairs of the indices for the vertices in the vertex array that we     *
      *  want to connect. Each point will be connected to its four surrounding *
@@ -100,5 +126,102 @@ pub fn generate_indices(ptr: *mut u32, nx_pts: u32, ny_pts: u32) {
         /*  End of horizontal for-loop.                                       */
     }
     /*  End of vertical for-loop.                                             */
+
+    return STATUS_OK;
+}
+/*  End of write_indices.                                                     */
+
+/*  Function for generating the wireframe for the animation. Returns one of   *
+ *  the status codes in lib.rs, and nothing is written on failure. This pairs *
+ *  with the row-major generate_mesh, for the vertices from                   *
+ *  generate_mesh_colmajor use generate_indices_colmajor instead.             */
+pub fn generate_indices(ptr: *mut u32, nx_pts: u32, ny_pts: u32) -> i32 {
+
+    /*  The array may hold the wireframe cached by generateIndicesCached.     */
+    invalidate_index_cache();
+    return write_indices(ptr, nx_pts, ny_pts);
 }
 /*  End of generate_indices.                                                  */
+
+#[cfg(test)]
+mod tests {
+    use super::generate_indices;
+    use crate::index_count::index_count;
+    use crate::TEST_LOCK;
+    use crate::{STATUS_DEGENERATE, STATUS_OK, STATUS_TOO_TALL, STATUS_TOO_WIDE};
+
+    /*  Value the buffers are filled with, to see which elements are written. */
+    const SENTINEL: u32 = u32::MAX;
+
+    /*  For a 3 by 2 grid the "L" shapes skip the edge going up from the top  *
+     *  row and the edge going right from the right column.                   */
+    #[test]
+    fn omits_edges_past_the_top_and_right() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let mut arr: [u32; 16] = [SENTINEL; 16];
+
+        assert_eq!(generate_indices(arr.as_mut_ptr(), 3, 2), STATUS_OK);
+
+        let expected: [u32; 14] = [
+            0, 3, 0, 1,
+            1, 4, 1, 2,
+            2, 5,
+            3, 4,
+            4, 5
+        ];
+
+        assert_eq!(arr[..14], expected);
+        assert_eq!(arr[14..], [SENTINEL; 2]);
+    }
+
+    /*  Exactly index_count(nx, ny) elements are written.                     */
+    #[test]
+    fn writes_index_count_elements() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let len: usize = index_count(6, 5) as usize;
+        let mut arr: [u32; 120] = [SENTINEL; 120];
+
+        assert_eq!(generate_indices(arr.as_mut_ptr(), 6, 5), STATUS_OK);
+        assert!(arr[..len].iter().all(|&value| value < 30));
+        assert!(arr[len..].iter().all(|&value| value == SENTINEL));
+    }
+
+    /*  A wide 512 by 128 grid writes exactly index_count(512, 128) elements, *
+     *  and every index refers to one of the points of the mesh.              */
+    #[test]
+    fn wide_grid_writes_index_count_elements() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let len: usize = index_count(512, 128) as usize;
+        let mut arr: Vec<u32> = vec![SENTINEL; len + 16];
+
+        assert_eq!(generate_indices(arr.as_mut_ptr(), 512, 128), STATUS_OK);
+        assert!(arr[..len].iter().all(|&value| value < 512 * 128));
+        assert!(arr[len..].iter().all(|&value| value == SENTINEL));
+    }
+
+    /*  Input that is too wide, too tall, or too small is rejected, and the   *
+     *  buffer is left alone.                                                 */
+    #[test]
+    fn rejects_oversize_and_degenerate_input() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let mut arr: [u32; 8] = [SENTINEL; 8];
+        let ptr: *mut u32 = arr.as_mut_ptr();
+
+        assert_eq!(generate_indices(ptr, 513, 2), STATUS_TOO_WIDE);
+        assert_eq!(generate_indices(ptr, 2, 513), STATUS_TOO_TALL);
+        assert_eq!(generate_indices(ptr, 1, 5), STATUS_DEGENERATE);
+        assert!(arr.iter().all(|&value| value == SENTINEL));
+    }
+
+    /*  A huge request is turned down before any slice is created, instead of *
+     *  making a slice of 10^10 points over a small buffer.                   */
+    #[test]
+    fn rejects_huge_input() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let mut arr: [u32; 4] = [SENTINEL; 4];
+        let ptr: *mut u32 = arr.as_mut_ptr();
+
+        assert_eq!(generate_indices(ptr, 100000, 100000), STATUS_TOO_WIDE);
+        assert!(arr.iter().all(|&value| value == SENTINEL));
+    }
+}
@@ -0,0 +1,93 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the wireframe indices, skipping the work if they are already *
+ *      in place.                                                             *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  The array and dimensions the last wireframe was written for.              */
+use crate::INDEX_CACHE;
+
+/*  Status code for success, returned by generate_indices.                    */
+use crate::STATUS_OK;
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  Function for computing the wireframe of the mesh, leaving the cache be.   */
+use crate::generate_indices::write_indices;
+
+/*  Function for generating the wireframe, unless the same array was already  *
+ *  filled in for the same dimensions. The indices only depend on the size of *
+ *  the mesh, not the rotation, so most frames can skip this. The cache is    *
+ *  keyed on the address as well as the dimensions, so writing into another   *
+ *  array is never skipped. Every other function that writes indices calls    *
+ *  invalidate_index_cache, so a stale array is never mistaken for this one.  *
+ *  Returns one of the status codes in lib.rs.                                */
+pub fn generate_indices_cached(ptr: *mut u32,
+                               nx_pts: u32, ny_pts: u32) -> i32 {
+
+    /*  The key for this call.                                                */
+    let key: (usize, u32, u32) = (ptr as usize, nx_pts, ny_pts);
+
+    /*  Hold the lock while generating, so no other call sees a half-written  *
+     *  array marked as valid.                                                */
+    let mut cache = lock(&INDEX_CACHE);
+
+    /*  The array already holds these exact indices, nothing to do.           */
+    if *cache == Some(key) {
+        return STATUS_OK;
+    }
+
+    /*  Compute the indices. They are only cached if this succeeded, since    *
+     *  nothing is written on failure.                                        */
+    let status: i32 = write_indices(ptr, nx_pts, ny_pts);
+
+    *cache = if status == STATUS_OK { Some(key) } else { None };
+    return status;
+}
+/*  End of generate_indices_cached.                                           */
+
+#[cfg(test)]
+mod tests {
+    use super::generate_indices_cached;
+    use crate::generate_indices_lod::generate_indices_lod;
+    use crate::lock::lock;
+    use crate::{INDEX_CACHE, STATUS_OK, TEST_LOCK};
+
+    /*  Writing other indices over the cached wireframe must forget it, so    *
+     *  the next cached call writes the full wireframe again.                 */
+    #[test]
+    fn other_writers_invalidate_the_cache() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let mut indices: [u32; 24] = [0; 24];
+        let ptr: *mut u32 = indices.as_mut_ptr();
+
+        assert_eq!(generate_indices_cached(ptr, 3, 3), STATUS_OK);
+        assert_eq!(*lock(&INDEX_CACHE), Some((ptr as usize, 3, 3)));
+        let expected: [u32; 24] = indices;
+
+        generate_indices_lod(ptr, 3, 3, 2);
+        assert_eq!(*lock(&INDEX_CACHE), None);
+
+        assert_eq!(generate_indices_cached(ptr, 3, 3), STATUS_OK);
+        assert_eq!(indices, expected);
+    }
+}
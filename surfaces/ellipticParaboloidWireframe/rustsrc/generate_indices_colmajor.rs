@@ -0,0 +1,111 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the wireframe indices for a mesh stored in column-major      *
+ *      order.                                                                *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Status codes returned to JavaScript, zero means success.                  */
+use crate::{STATUS_OK, STATUS_DEGENERATE};
+
+/*  Number of elements in the index array for the given dimensions.           */
+use crate::index_count::index_count;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Function for forgetting the wireframe cached by generateIndicesCached.    */
+use crate::invalidate_index_cache::invalidate_index_cache;
+
+/*  Function for generating the wireframe for generate_mesh_colmajor. The     *
+ *  lines are the same as those from generate_indices, only the vertex        *
+ *  numbering differs. Returns one of the status codes in lib.rs.             */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_indices_colmajor(ptr: *mut u32,
+                                 nx_pts: u32, ny_pts: u32) -> i32 {
+
+    /*  A grid needs at least two points along each axis. Otherwise the       *
+     *  length of the array underflows, so return early.                      */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return STATUS_DEGENERATE;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    let status: i32 = capacity_status(nx_pts, ny_pts);
+
+    if status != STATUS_OK {
+        return status;
+    }
+
+    /*  The number of lines does not depend on the order of the vertices.     */
+    let len: usize = index_count(nx_pts, ny_pts) as usize;
+
+    /*  The array may hold the wireframe cached by generateIndicesCached.     */
+    invalidate_index_cache();
+
+    /*  Convert the pointer into a slice.                                     */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Loop over the columns, matching the order of generate_mesh_colmajor.  */
+    for x_index in 0..nx_pts {
+
+        /*  The indices are column-major, meaning index = x * height + y. The *
+         *  shift factor only depends on the x-component, compute this.       */
+        let shift: u32 = x_index * ny_pts;
+
+        /*  The horizontal component is now fixed, loop through the vertical. */
+        for y_index in 0..ny_pts {
+
+            /*  The index for (x, y) is x * height + y.                       */
+            let index00: u32 = shift + y_index;
+
+            /*  The point directly above the current point is next in memory. */
+            let index10: u32 = index00 + 1;
+
+            /*  The point to the right is one whole column further along.     */
+            let index01: u32 = index00 + ny_pts;
+
+            /*  Same "L" shape as generate_indices. Skip the vertical line at *
+             *  the top edge of the rectangle.                                */
+            if y_index != ny_pts - 1 {
+                arr[index] = index00;
+                arr[index + 1] = index10;
+                index += 2;
+            }
+
+            /*  And skip the horizontal line at the right edge.               */
+            if x_index != nx_pts - 1 {
+                arr[index] = index00;
+                arr[index + 1] = index01;
+                index += 2;
+            }
+        }
+        /*  End of vertical for-loop.                                         */
+    }
+    /*  End of horizontal for-loop.                                           */
+
+    return STATUS_OK;
+}
+/*  End of generate_indices_colmajor.                                         */
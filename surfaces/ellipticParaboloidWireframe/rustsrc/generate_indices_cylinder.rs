@@ -0,0 +1,105 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the wireframe indices for the cylinder, closing the seam and *
+ *      connecting the caps.                                                  *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Function for the wireframe of a grid, joining the right and left edges.   */
+use crate::generate_indices_seam::generate_indices_seam;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks against the current capacity of the buffers.                       */
+use crate::buffer_capacity::{capacity_status, index_capacity};
+
+/*  Function for forgetting the wireframe cached by generateIndicesCached.    */
+use crate::invalidate_index_cache::invalidate_index_cache;
+
+/*  Function for generating the wireframe for generate_cylinder. The side is  *
+ *  the grid with its seam closed. If capped, each point of the bottom and    *
+ *  top rings is joined to the center of its disk, making a fan of spokes.    *
+ *  Returns the number of indices written, or zero if the input is invalid.   */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_indices_cylinder(ptr: *mut u32, n_theta: u32, n_height: u32,
+                                 capped: bool) -> u32 {
+
+    /*  A grid needs at least two points along each axis. Otherwise the       *
+     *  length of the array underflows, so return early.                      */
+    if (n_theta < 2) || (n_height < 2) {
+        return 0;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(n_theta, n_height) != STATUS_OK {
+        return 0;
+    }
+
+    /*  The side of the cylinder is a grid with a closed seam, which has 2    *
+     *  (2wh - w) elements. Each cap adds one spoke per point on its ring.    */
+    let number_of_points: u32 = n_theta * n_height;
+    let side_len: u32 = 2 * (2 * number_of_points - n_theta);
+    let len: u32 = side_len + if capped { 4 * n_theta } else { 0 };
+
+    /*  Closing the seam and adding the spokes gives more elements than the   *
+     *  plain grid, so a grid that fills the index buffer leaves no room for  *
+     *  them. Check the total length before anything is written.              */
+    if (len as usize) > index_capacity() {
+        return 0;
+    }
+
+    /*  The side is the same as the other surfaces with a seam.               */
+    generate_indices_seam(ptr, n_theta, n_height, true, false);
+
+    if !capped {
+        return len;
+    }
+
+    /*  The array may hold the wireframe cached by generateIndicesCached.     */
+    invalidate_index_cache();
+
+    /*  Convert the pointer into a slice, for the spokes.                     */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len as usize) };
+
+    /*  The two centers come right after the grid, bottom and then top.       */
+    let bottom_center: u32 = number_of_points;
+    let top_center: u32 = number_of_points + 1;
+
+    /*  The first point of the top ring.                                      */
+    let top_shift: u32 = (n_height - 1) * n_theta;
+
+    /*  Start writing after the lines for the side.                           */
+    let mut index: usize = side_len as usize;
+
+    /*  Join every point on the two rings to the center of its disk.          */
+    for theta_index in 0..n_theta {
+        arr[index] = bottom_center;
+        arr[index + 1] = theta_index;
+        arr[index + 2] = top_center;
+        arr[index + 3] = top_shift + theta_index;
+        index += 4;
+    }
+
+    return len;
+}
+/*  End of generate_indices_cylinder.                                         */
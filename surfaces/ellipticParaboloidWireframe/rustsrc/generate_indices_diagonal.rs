@@ -0,0 +1,133 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the indices for a wireframe mesh with the diagonals of each  *
+ *      square included.                                                      *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Computes the length of the buffer, checking for overflow.                 */
+use crate::checked_len::checked_len;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Function for forgetting the wireframe cached by generateIndicesCached.    */
+use crate::invalidate_index_cache::invalidate_index_cache;
+
+/*  Function for generating the wireframe with diagonal edges. The array      *
+ *  needs 2 (2wh - w - h) + 2 (w - 1)(h - 1) elements, more than the index    *
+ *  buffer holds, so it must be allocated by the caller.                      */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_indices_diagonal(ptr: *mut u32, nx_pts: u32, ny_pts: u32) {
+
+    /*  A grid needs at least two points along each axis. Otherwise the       *
+     *  length of the array underflows, so return early.                      */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(nx_pts, ny_pts) != STATUS_OK {
+        return;
+    }
+
+    /*  The "L" shaped edges are the same as in generate_indices. There is    *
+     *  also one diagonal for each of the (w - 1) (h - 1) squares, where w is *
+     *  the width and h is the height, and each edge needs two indices. Both  *
+     *  products are checked for overflow.                                    */
+    let diagonals: usize = match checked_len(nx_pts - 1, ny_pts - 1, 2) {
+        Some(diagonals) => diagonals,
+        None => return,
+    };
+
+    let four_wh: usize = match checked_len(nx_pts, ny_pts, 4) {
+        Some(four_wh) => four_wh,
+        None => return,
+    };
+
+    /*  There are 2 (2wh - w - h) elements for the "L" shaped edges.          */
+    let len: usize = four_wh - 2 * ((nx_pts + ny_pts) as usize) + diagonals;
+
+    /*  The array may hold the wireframe cached by generateIndicesCached.     */
+    invalidate_index_cache();
+
+    /*  Convert the pointer into a slice.                                     */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Create the lines in the same manner as generate_indices, adding the   *
+     *  diagonal of the square whose bottom left corner is the current point. */
+    for y_index in 0..ny_pts {
+
+        /*  The indices are row-major, meaning index = y * width + x. The     *
+         *  shift factor only depends on the y-component, compute this.       */
+        let shift: u32 = y_index * nx_pts;
+
+        /*  The vertical component is now fixed, loop through the horizontal. */
+        for x_index in 0..nx_pts {
+
+            /*  The current index is the shift plus horizontal index.         */
+            let index00: u32 = shift + x_index;
+
+            /*  The point directly after the current point, in the horizontal.*/
+            let index01: u32 = index00 + 1;
+
+            /*  The point directly above the current point, in the vertical.  */
+            let index10: u32 = index00 + nx_pts;
+
+            /*  The point diagonal to the current point, up and to the right. */
+            let index11: u32 = index10 + 1;
+
+            /*  The vertical edge, skipped along the top of the rectangle.    */
+            if y_index != ny_pts - 1 {
+                arr[index] = index00;
+                arr[index + 1] = index10;
+                index += 2;
+            }
+
+            /*  The horizontal edge, skipped along the right edge.            */
+            if x_index != nx_pts - 1 {
+                arr[index] = index00;
+                arr[index + 1] = index01;
+                index += 2;
+            }
+
+            /*  The diagonal only exists if the current point is the bottom   *
+             *  left corner of a square, meaning it is on neither the top nor *
+             *  the right edge. This is the same diagonal used to split the   *
+             *  square in generate_triangle_indices.                          */
+            if (y_index != ny_pts - 1) && (x_index != nx_pts - 1) {
+                arr[index] = index00;
+                arr[index + 1] = index11;
+                index += 2;
+            }
+        }
+        /*  End of horizontal for-loop.                                       */
+    }
+    /*  End of vertical for-loop.                                             */
+}
+/*  End of generate_indices_diagonal.                                         */
@@ -0,0 +1,92 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Creates the wireframe for a surface sampled in polar coordinates.     *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks against the current capacity of the buffers.                       */
+use crate::buffer_capacity::{capacity_status, index_capacity};
+
+/*  Function for the wireframe of a grid, joining the right and left edges.   */
+use crate::generate_indices_seam::generate_indices_seam;
+
+/*  Function for forgetting the wireframe cached by generateIndicesCached.    */
+use crate::invalidate_index_cache::invalidate_index_cache;
+
+/*  Function for generating the wireframe for generate_mesh_disk. The rings   *
+ *  form a grid with its seam closed, and each point of the innermost ring is *
+ *  joined to the center, which is the point right after the grid. Returns    *
+ *  the number of indices written, or zero if the input is invalid.           */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_indices_disk(ptr: *mut u32, n_r: u32, n_theta: u32) -> u32 {
+
+    /*  A grid needs at least two points along each axis. Otherwise the       *
+     *  length of the array underflows, so return early.                      */
+    if (n_r < 2) || (n_theta < 2) {
+        return 0;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(n_theta, n_r) != STATUS_OK {
+        return 0;
+    }
+
+    /*  The rings are a grid with a closed seam, which has 2 (2wh - w)        *
+     *  elements. The spokes add one line for each point on the inner ring.   */
+    let number_of_points: u32 = n_theta * n_r;
+    let ring_len: u32 = 2 * (2 * number_of_points - n_theta);
+    let len: u32 = ring_len + 2 * n_theta;
+
+    /*  Closing the seam and adding the spokes gives more elements than the   *
+     *  plain grid, so check the total length before anything is written.     */
+    if (len as usize) > index_capacity() {
+        return 0;
+    }
+
+    /*  The rings are the same as the other surfaces with a seam.             */
+    generate_indices_seam(ptr, n_theta, n_r, true, false);
+
+    /*  The array may hold the wireframe cached by generateIndicesCached.     */
+    invalidate_index_cache();
+
+    /*  Convert the pointer into a slice, for the spokes.                     */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len as usize) };
+
+    /*  The center comes right after the grid.                                */
+    let center: u32 = number_of_points;
+
+    /*  Start writing after the lines for the rings.                          */
+    let mut index: usize = ring_len as usize;
+
+    /*  Join every point on the inner ring, the first row, to the center.     */
+    for theta_index in 0..n_theta {
+        arr[index] = center;
+        arr[index + 1] = theta_index;
+        index += 2;
+    }
+
+    return len;
+}
+/*  End of generate_indices_disk.                                             */
@@ -0,0 +1,106 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the wireframe indices for the tiles made by                  *
+ *      generate_mesh_grid.                                                   *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Status code for success, returned by generate_indices.                    */
+use crate::STATUS_OK;
+
+/*  Function for computing the wireframe of a single mesh.                    */
+use crate::generate_indices::generate_indices;
+
+/*  Number of elements in the index array for the given dimensions.           */
+use crate::index_count::index_count;
+
+/*  Number of elements the index buffer currently has room for.               */
+use crate::buffer_capacity::index_capacity;
+
+/*  Function for generating the wireframe for all cols * rows tiles from      *
+ *  generate_mesh_grid. Each tile gets the same lines as generate_indices,    *
+ *  offset by the number of vertices in the tiles before it. Returns the      *
+ *  total number of indices, or zero if the input is invalid.                 */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_indices_grid(ptr: *mut u32, nx_pts: u32, ny_pts: u32,
+                             cols: u32, rows: u32) -> u32 {
+
+    /*  There must be at least one tile.                                      */
+    if (cols == 0) || (rows == 0) {
+        return 0;
+    }
+
+    /*  A grid needs at least two points along each axis. Otherwise the       *
+     *  length of the array underflows, so return early.                      */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return 0;
+    }
+
+    /*  The largest vertex number must fit in a u32, same as the vertices.    */
+    if cols.checked_mul(rows)
+        .and_then(|n| n.checked_mul(nx_pts))
+        .and_then(|n| n.checked_mul(ny_pts))
+        .and_then(|n| n.checked_mul(3))
+        .is_none() {
+        return 0;
+    }
+
+    /*  The number of indices, and vertices, for a single tile.               */
+    let tile_len: u32 = index_count(nx_pts, ny_pts);
+    let tile_pts: u32 = nx_pts * ny_pts;
+
+    /*  The total number of indices must fit in a u32 too.                    */
+    let total: u32 = match tile_len.checked_mul(cols * rows) {
+        Some(n) => n,
+        None => return 0,
+    };
+
+    /*  generate_indices checks that a single tile fits in the index buffer,  *
+     *  but all of the tiles together must fit as well.                       */
+    if (total as usize) > index_capacity() {
+        return 0;
+    }
+
+    for tile_index in 0..(cols * rows) {
+
+        /*  The first element of this tile in the array.                      */
+        let start: usize = (tile_index * tile_len) as usize;
+        let tile_ptr: *mut u32 = unsafe { ptr.add(start) };
+
+        /*  generate_indices does all of the checks on nx_pts and ny_pts.     */
+        if generate_indices(tile_ptr, nx_pts, ny_pts) != STATUS_OK {
+            return 0;
+        }
+
+        /*  Shift the indices past the vertices of the previous tiles.        */
+        let base: u32 = tile_index * tile_pts;
+        let tile = unsafe {
+            std::slice::from_raw_parts_mut(tile_ptr, tile_len as usize)
+        };
+
+        for index in tile.iter_mut() {
+            *index += base;
+        }
+    }
+
+    return total;
+}
+/*  End of generate_indices_grid.                                             */
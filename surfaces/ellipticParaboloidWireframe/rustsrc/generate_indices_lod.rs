@@ -0,0 +1,126 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes a coarser wireframe that only uses every few vertices of the *
+ *      mesh.                                                                 *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Number of elements in the index array for the given dimensions.           */
+use crate::index_count::index_count;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Function for forgetting the wireframe cached by generateIndicesCached.    */
+use crate::invalidate_index_cache::invalidate_index_cache;
+
+/*  Number of vertices kept along an axis with n points, taking every stride  *
+ *  points. The last point is always kept so the wireframe reaches the edge.  */
+fn lod_count(n_pts: u32, stride: u32) -> u32 {
+    return (n_pts - 1).div_ceil(stride) + 1;
+}
+
+/*  The index of the k^th kept vertex along an axis. This is k * stride,      *
+ *  except for the last one, which is moved back onto the edge of the grid.   */
+fn lod_index(k: u32, n_pts: u32, stride: u32) -> u32 {
+    return (k * stride).min(n_pts - 1);
+}
+
+/*  Function for generating a wireframe for the mesh from generate_mesh that  *
+ *  only connects every stride^th vertex along each axis, for drawing the     *
+ *  surface when it is far away. The vertex array does not change, so one     *
+ *  dense mesh serves every level of detail. The array must have room for     *
+ *  generate_indices, which is the most this can write. A stride of 1 gives   *
+ *  the same wireframe as generate_indices. Returns the number of edges       *
+ *  written, or zero if the input is invalid.                                 */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_indices_lod(ptr: *mut u32, nx_pts: u32, ny_pts: u32,
+                            stride: u32) -> u32 {
+
+    /*  A grid needs at least two points along each axis, and the stride must *
+     *  be positive.                                                          */
+    if (nx_pts < 2) || (ny_pts < 2) || (stride == 0) {
+        return 0;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(nx_pts, ny_pts) != STATUS_OK {
+        return 0;
+    }
+
+    /*  The coarse grid has at most as many lines as the full one.            */
+    let len: usize = index_count(nx_pts, ny_pts) as usize;
+
+    /*  The array may hold the wireframe cached by generateIndicesCached.     */
+    invalidate_index_cache();
+
+    /*  Convert the pointer into a slice.                                     */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  The dimensions of the coarse grid.                                    */
+    let nx_lod: u32 = lod_count(nx_pts, stride);
+    let ny_lod: u32 = lod_count(ny_pts, stride);
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Same "L" shapes as generate_indices, but over the coarse grid. The    *
+     *  vertex numbers are those of the full grid, index = y * width + x.     */
+    for y_lod in 0..ny_lod {
+
+        /*  The row in the full grid, and the row above it in the coarse grid.*/
+        let y_index: u32 = lod_index(y_lod, ny_pts, stride);
+        let shift: u32 = y_index * nx_pts;
+
+        for x_lod in 0..nx_lod {
+
+            /*  The current point in the full grid.                           */
+            let x_index: u32 = lod_index(x_lod, nx_pts, stride);
+            let index00: u32 = shift + x_index;
+
+            /*  The vertical line, unless we are at the top edge.             */
+            if y_lod != ny_lod - 1 {
+                let y_next: u32 = lod_index(y_lod + 1, ny_pts, stride);
+                arr[index] = index00;
+                arr[index + 1] = y_next * nx_pts + x_index;
+                index += 2;
+            }
+
+            /*  The horizontal line, unless we are at the right edge.         */
+            if x_lod != nx_lod - 1 {
+                let x_next: u32 = lod_index(x_lod + 1, nx_pts, stride);
+                arr[index] = index00;
+                arr[index + 1] = shift + x_next;
+                index += 2;
+            }
+        }
+        /*  End of horizontal for-loop.                                       */
+    }
+    /*  End of vertical for-loop.                                             */
+
+    /*  Each edge is two indices.                                             */
+    return (index / 2) as u32;
+}
+/*  End of generate_indices_lod.                                              */
@@ -0,0 +1,74 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Creates the line segments for a closed polyline.                      *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Number of points the mesh buffer currently has room for.                  */
+use crate::buffer_capacity::point_capacity;
+
+/*  Function for forgetting the wireframe cached by generateIndicesCached.    */
+use crate::invalidate_index_cache::invalidate_index_cache;
+
+/*  Function for generating the wireframe for a closed curve of n points,     *
+ *  such as the one from generate_torus_knot. Point i is joined to point i +  *
+ *  1, and the last point is joined back to the first, giving n line          *
+ *  segments. Returns the number of elements written, which is 2n, or zero if *
+ *  the input is invalid.                                                     */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_indices_loop(ptr: *mut u32, n_pts: u32) -> u32 {
+
+    /*  A closed loop needs at least three points to enclose anything.        */
+    if n_pts < 3 {
+        return 0;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  The index buffer holds more than 2 elements per point, so checking    *
+     *  against the size of the mesh buffer is enough.                        */
+    if (n_pts as usize) > point_capacity() {
+        return 0;
+    }
+
+    /*  The array may hold the wireframe cached by generateIndicesCached.     */
+    invalidate_index_cache();
+
+    /*  Each segment needs two indices, one per endpoint.                     */
+    let len: usize = (2 * n_pts) as usize;
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    for point in 0..n_pts {
+
+        /*  The next point, wrapping around at the end to close the loop.     */
+        let next: u32 = if point + 1 == n_pts { 0 } else { point + 1 };
+
+        arr[index] = point;
+        arr[index + 1] = next;
+        index += 2;
+    }
+    /*  End of for-loop for the segments.                                     */
+
+    return 2 * n_pts;
+}
+/*  End of generate_indices_loop.                                             */
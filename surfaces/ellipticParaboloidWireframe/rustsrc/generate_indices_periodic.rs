@@ -0,0 +1,157 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the indices for a wireframe mesh whose edges may be joined to*
+ *      the opposite edges, for surfaces that are periodic in one or both     *
+ *      directions.                                                           *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Number of elements the periodic index buffer has room for.                */
+use crate::buffer_capacity::periodic_index_capacity;
+
+/*  Computes the length of the buffer, checking for overflow.                 */
+use crate::checked_len::checked_len;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Function for forgetting the wireframe cached by generateIndicesCached.    */
+use crate::invalidate_index_cache::invalidate_index_cache;
+
+/*  Function for generating the wireframe for periodic surfaces. If wrap_x is *
+ *  set, the right-most column is joined to the left-most column. If flip is  *
+ *  set as well, the join has a half-twist, as for the Mobius strip, and row  *
+ *  y is joined to row h - 1 - y, where h is the height. If wrap_y is set,    *
+ *  the top row is joined to the bottom row.                                  */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_indices_periodic(ptr: *mut u32, nx_pts: u32, ny_pts: u32,
+                                 wrap_x: bool, wrap_y: bool, flip: bool) {
+
+    /*  A grid needs at least two points along each axis. Otherwise the       *
+     *  length of the array underflows, so return early.                      */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big. This is done before the slice   *
+     *  is created, since a slice longer than the allocation is undefined     *
+     *  behavior even if the extra elements are never touched.                */
+    if capacity_status(nx_pts, ny_pts) != STATUS_OK {
+        return;
+    }
+
+    /*  The "L" shaped edges are the same as in generate_indices, which is    *
+     *  2 (2wh - w - h) elements. Wrapping in x adds one edge for each row,   *
+     *  and wrapping in y adds one edge for each column. Each edge needs two  *
+     *  indices, so with both there are 4wh elements, more than INDEX_SIZE.   *
+     *  The product is checked for overflow.                                  */
+    let four_wh: usize = match checked_len(nx_pts, ny_pts, 4) {
+        Some(four_wh) => four_wh,
+        None => return,
+    };
+
+    let mut len: usize = four_wh;
+
+    if !wrap_x {
+        len -= (2 * ny_pts) as usize;
+    }
+
+    if !wrap_y {
+        len -= (2 * nx_pts) as usize;
+    }
+
+    /*  The array should be the one from get_periodic_index_buffer, which has *
+     *  room for 4WH elements, W and H being the width and height given to    *
+     *  init_buffers.                                                         */
+    if len > periodic_index_capacity() {
+        return;
+    }
+
+    /*  The array may hold the wireframe cached by generateIndicesCached.     */
+    invalidate_index_cache();
+
+    /*  Convert the pointer into a slice.                                     */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Create the lines in the same manner as generate_indices, adding the   *
+     *  lines across the seams if requested.                                  */
+    for y_index in 0..ny_pts {
+
+        /*  The indices are row-major, meaning index = y * width + x. The     *
+         *  shift factor only depends on the y-component, compute this.       */
+        let shift: u32 = y_index * nx_pts;
+
+        /*  The row the right edge is joined to. Without a twist this is the  *
+         *  same row. With a twist the surface comes back upside down.        */
+        let seam_shift: u32 = if flip {
+            (ny_pts - 1 - y_index) * nx_pts
+        } else {
+            shift
+        };
+
+        /*  The vertical component is now fixed, loop through the horizontal. */
+        for x_index in 0..nx_pts {
+
+            /*  The current index is the shift plus horizontal index.         */
+            let index00: u32 = shift + x_index;
+
+            /*  The point directly after the current point, in the horizontal.*/
+            let index01: u32 = index00 + 1;
+
+            /*  The point directly above the current point, in the vertical.  */
+            let index10: u32 = index00 + nx_pts;
+
+            /*  The vertical edge. Along the top row, the point above is the  *
+             *  point in the bottom row with the same x index.                */
+            if y_index != ny_pts - 1 {
+                arr[index] = index00;
+                arr[index + 1] = index10;
+                index += 2;
+            } else if wrap_y {
+                arr[index] = index00;
+                arr[index + 1] = x_index;
+                index += 2;
+            }
+
+            /*  The horizontal edge. Along the right edge, the point after is *
+             *  the left-most point in the row computed above.                */
+            if x_index != nx_pts - 1 {
+                arr[index] = index00;
+                arr[index + 1] = index01;
+                index += 2;
+            } else if wrap_x {
+                arr[index] = index00;
+                arr[index + 1] = seam_shift;
+                index += 2;
+            }
+        }
+        /*  End of horizontal for-loop.                                       */
+    }
+    /*  End of vertical for-loop.                                             */
+}
+/*  End of generate_indices_periodic.                                         */
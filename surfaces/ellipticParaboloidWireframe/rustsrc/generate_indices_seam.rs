@@ -0,0 +1,91 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the indices for a wireframe mesh, optionally connecting the  *
+ *      right-most column back to the left-most one, with or without a half-  *
+ *      twist.                                                                *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Number of elements the index buffer currently has room for.               */
+use crate::buffer_capacity::index_capacity;
+
+/*  Computes the length of the buffer, checking for overflow.                 */
+use crate::checked_len::checked_len;
+
+/*  Function for generating the wireframe, which does the actual writing.     */
+use crate::generate_indices_periodic::generate_indices_periodic;
+
+/*  Function for generating the wireframe for surfaces with a seam. If flip   *
+ *  is set, the seam is joined with a half-twist, as for the Mobius strip.    *
+ *  This is generate_indices_periodic joining only the seam in x, checked     *
+ *  against the index buffer rather than the larger periodic one.             */
+pub fn generate_indices_seam(ptr: *mut u32, nx_pts: u32, ny_pts: u32,
+                             wrap: bool, flip: bool) {
+
+    /*  A grid needs at least two points along each axis. Otherwise the       *
+     *  length of the array underflows, so return early.                      */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return;
+    }
+
+    /*  Both counts below are less than 4wh, which is checked for overflow.   */
+    let four_wh: usize = match checked_len(nx_pts, ny_pts, 4) {
+        Some(four_wh) => four_wh,
+        None => return,
+    };
+
+    /*  Without wrapping there are 2 (2wh - w - h) elements in the index      *
+     *  array, the same as generate_indices. Closing the seam adds one        *
+     *  horizontal edge for each row, which is h more edges, or 2h more       *
+     *  elements. This gives 2 (2wh - w) elements in total.                   */
+    let len: usize = if wrap {
+        four_wh - 2 * (nx_pts as usize)
+    } else {
+        four_wh - 2 * ((nx_pts + ny_pts) as usize)
+    };
+
+    /*  The index buffer holds 2 (2WH - W - H) elements, W and H being the    *
+     *  width and height given to init_buffers, so a wrapped grid of full     *
+     *  size does not fit. The largest wrapped grids need to be a little      *
+     *  smaller. generate_indices_periodic checks the dimensions as well.     */
+    if len > index_capacity() {
+        return;
+    }
+
+    generate_indices_periodic(ptr, nx_pts, ny_pts, wrap, false, flip);
+}
+/*  End of generate_indices_seam.                                             */
+
+#[cfg(test)]
+mod tests {
+    use super::generate_indices_seam;
+    use crate::TEST_LOCK;
+
+    /*  A 2x2 grid joined with a half-twist. Row 0 is joined to row 1 and row *
+     *  1 back to row 0, after the usual "L" shaped edges of each point.      */
+    #[test]
+    fn twisted_seam_joins_opposite_rows() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let mut indices: [u32; 12] = [0; 12];
+
+        generate_indices_seam(indices.as_mut_ptr(), 2, 2, true, true);
+        assert_eq!(indices, [0, 2, 0, 1, 1, 3, 1, 2, 2, 3, 3, 0]);
+    }
+}
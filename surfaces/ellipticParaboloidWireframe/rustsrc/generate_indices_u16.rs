@@ -0,0 +1,110 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the wireframe indices as 16-bit integers for older GPUs.     *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Status codes returned to JavaScript, zero means success.                  */
+use crate::{STATUS_OK, STATUS_TOO_LARGE, STATUS_DEGENERATE};
+
+/*  Number of elements in the index array for the given dimensions.           */
+use crate::index_count::index_count;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Function for forgetting the wireframe cached by generateIndicesCached.    */
+use crate::invalidate_index_cache::invalidate_index_cache;
+
+/*  Function for generating the same wireframe as generate_indices, but with  *
+ *  u16 indices. WebGL1 without the OES_element_index_uint extension only     *
+ *  accepts 16-bit index buffers. Returns one of the status codes in lib.rs.  */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_indices_u16(ptr: *mut u16, nx_pts: u32, ny_pts: u32) -> i32 {
+
+    /*  A grid needs at least two points along each axis. Otherwise the       *
+     *  length of the array underflows, so return early.                      */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return STATUS_DEGENERATE;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    let status: i32 = capacity_status(nx_pts, ny_pts);
+
+    if status != STATUS_OK {
+        return status;
+    }
+
+    /*  The largest index is nx_pts * ny_pts - 1, which must fit in a u16.    *
+     *  Both factors are at most 512 here, so the product does not overflow.  */
+    if nx_pts * ny_pts > 65536 {
+        return STATUS_TOO_LARGE;
+    }
+
+    /*  The number of elements is the same as it is for generate_indices.     */
+    let len: usize = index_count(nx_pts, ny_pts) as usize;
+
+    /*  The array may hold the wireframe cached by generateIndicesCached.     */
+    invalidate_index_cache();
+
+    /*  Convert the pointer into a slice.                                     */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Same loop as generate_indices. Every index written is a vertex of the *
+     *  mesh, so by the check above the conversions to u16 are exact.         */
+    for y_index in 0..ny_pts {
+
+        /*  The indices are row-major, meaning index = y * width + x.         */
+        let shift: u32 = y_index * nx_pts;
+
+        /*  The vertical component is now fixed, loop through the horizontal. */
+        for x_index in 0..nx_pts {
+
+            /*  The point itself, the one to its right, and the one above it. */
+            let index00: u32 = shift + x_index;
+            let index01: u32 = index00 + 1;
+            let index10: u32 = index00 + nx_pts;
+
+            /*  The vertical part of the "L", skipped at the top edge.        */
+            if y_index != ny_pts - 1 {
+                arr[index] = index00 as u16;
+                arr[index + 1] = index10 as u16;
+                index += 2;
+            }
+
+            /*  The horizontal part of the "L", skipped at the right edge.    */
+            if x_index != nx_pts - 1 {
+                arr[index] = index00 as u16;
+                arr[index + 1] = index01 as u16;
+                index += 2;
+            }
+        }
+        /*  End of horizontal for-loop.                                       */
+    }
+    /*  End of vertical for-loop.                                             */
+
+    return STATUS_OK;
+}
+/*  End of generate_indices_u16.                                              */
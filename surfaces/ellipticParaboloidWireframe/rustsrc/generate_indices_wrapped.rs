@@ -0,0 +1,38 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the indices for a wireframe mesh, optionally connecting the  *
+ *      right-most column back to the left-most one.                          *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Function for generating the wireframe with an optional twisted seam.      */
+use crate::generate_indices_seam::generate_indices_seam;
+
+/*  Function for generating the wireframe for surfaces with a seam. This is   *
+ *  generate_indices_seam without the twist, and so generate_indices_periodic *
+ *  joining only the seam in x.                                               */
+pub fn generate_indices_wrapped(ptr: *mut u32, nx_pts: u32,
+                                ny_pts: u32, wrap: bool) {
+
+    /*  The seam of the torus, sphere, and similar surfaces has no twist. The *
+     *  maximum size check is done by generate_indices_seam.                  */
+    generate_indices_seam(ptr, nx_pts, ny_pts, wrap, false);
+}
+/*  End of generate_indices_wrapped.                                          */
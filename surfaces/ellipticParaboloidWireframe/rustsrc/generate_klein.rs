@@ -0,0 +1,127 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the locations of the points in the mesh for the figure-8     *
+ *      immersion of the Klein bottle.                                        *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Radius of the circle the figure-8 cross section is swept around.          */
+pub use crate::KLEIN_RADIUS;
+
+/*  Both parameters run over the full circle.                                 */
+use std::f32::consts::PI;
+
+/*  Computes the length of the buffer, checking for overflow.                 */
+use crate::checked_len::checked_len;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Functions for converting the points for y up, see set_up_axis.            */
+use crate::up_axis::{is_y_up, convert_up_axis};
+
+/*  Function for generating the mesh for a Klein bottle.                      */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_klein(ptr: *mut f32, n_u: u32, n_v: u32) {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (n_u < 2) || (n_v < 2) {
+        return;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(n_u, n_v) != STATUS_OK {
+        return;
+    }
+
+    /*  The size of the array passed to us is given by the number of points   *
+     *  in the mesh, which is simply n_u times n_v. Each point is 3 f32's.    *
+     *  The product is checked for overflow.                                  */
+    let len: usize = match checked_len(n_u, n_v, 3) {
+        Some(len) => len,
+        None => return,
+    };
+
+    /*  Get a slice for the data.                                             */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Step sizes for the two angles. The angle u, which goes around the     *
+     *  central axis, runs over [0, 2 pi) and omits the right endpoint. The   *
+     *  point (2 pi, v) is the point (0, 2 pi - v), so the seam is closed     *
+     *  with a flip, as for the Mobius strip. The angle v, which goes around  *
+     *  the figure-8, runs over the closed interval [0, 2 pi]. The top row of *
+     *  the mesh duplicates the bottom row, and row y is flipped onto row n_v *
+     *  - 1 - y, which is what generate_indices_seam expects.                 */
+    let du: f32 = 2.0 * PI / (n_u as f32);
+    let dv: f32 = 2.0 * PI / ((n_v - 1) as f32);
+
+    /*  Whether to convert the points for y up, see set_up_axis.              */
+    let y_up: bool = is_y_up();
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Loop through the angle around the figure-8. Since this is the outer   *
+     *  for-loop, the array is indexed in row-major fashion with u as the     *
+     *  horizontal axis. That is, index = v * n_u + u. The wireframe is given *
+     *  by generate_indices_seam(ptr, n_u, n_v, true, true).                  */
+    for v_index in 0..n_v {
+
+        /*  Convert the index to an angle. The figure-8 uses v and 2v.        */
+        let v: f32 = (v_index as f32) * dv;
+        let sin_v: f32 = v.sin();
+        let sin_2v: f32 = (2.0 * v).sin();
+
+        /*  Loop through the angle around the central axis.                   */
+        for u_index in 0..n_u {
+
+            /*  Convert the index to an angle. The figure-8 makes a half turn *
+             *  as we go around, so we also need the half angle.              */
+            let u: f32 = (u_index as f32) * du;
+            let (sin_half_u, cos_half_u): (f32, f32) = (0.5 * u).sin_cos();
+
+            /*  The distance from the central axis.                           */
+            let rho: f32 =
+                KLEIN_RADIUS + cos_half_u * sin_v - sin_half_u * sin_2v;
+
+            /*  Add this point to our vertex array. The figure-8 immersion is:*
+             *                                                                *
+             *        x = (r + cos(u/2) sin(v) - sin(u/2) sin(2v)) cos(u)     *
+             *        y = (r + cos(u/2) sin(v) - sin(u/2) sin(2v)) sin(u)     *
+             *        z = sin(u/2) sin(v) + cos(u/2) sin(2v)                  */
+            arr[index] = rho * u.cos();
+            arr[index + 1] = rho * u.sin();
+            arr[index + 2] = sin_half_u * sin_v + cos_half_u * sin_2v;
+            convert_up_axis(arr, index, y_up);
+
+            /*  Move on to the next point in the mesh. A point needs 3 f32's. */
+            index += 3;
+        }
+        /*  End of u for-loop.                                                */
+    }
+    /*  End of v for-loop.                                                    */
+}
+/*  End of generate_klein.                                                    */
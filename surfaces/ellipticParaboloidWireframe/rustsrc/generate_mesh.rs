@@ -21,71 +21,110 @@
  *  Date:       November 3, 2025                                              *
  ******************************************************************************/
 
-/*  Maximum number of pixels in the vertical and horizontal axes.             */
-pub use crate::{MAX_HEIGHT, MAX_WIDTH};
-
 /*  Physical width and height of the surface (projection onto the xy plane).  */
 pub use crate::{PARABOLOID_WIDTH, PARABOLOID_HEIGHT};
 
 /*  Left-most and bottom-most extremes of the surface (projected to xy plane).*/
 pub use crate::{PARABOLOID_X_START, PARABOLOID_Y_START};
 
-/*  Function for generating the mesh for the surface by calculating vertices. */
-pub fn generate_mesh(ptr: *mut f32, nx_pts: u32, ny_pts: u32) {
-
-    /*  The size of the array passed to us is given by the number of points   *
-     *  in the mesh, which is simply the width times the height.              */
-    let len: usize = (3 * nx_pts * ny_pts) as usize;
-
-    /*  Get a slice for the data.                                             */
-    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+/*  Function for generating the mesh over an arbitrary rectangle.             */
+use crate::generate_mesh_domain::generate_mesh_domain;
 
-    /*  Step sizes in the horizontal and vertical axes.                       */
-    let dx: f32 = PARABOLOID_WIDTH / ((nx_pts - 1) as f32);
-    let dy: f32 = PARABOLOID_HEIGHT / ((ny_pts - 1) as f32);
+/*  Function for generating the mesh for the surface by calculating vertices. *
+ *  Returns one of the status codes in lib.rs.                                */
+pub fn generate_mesh(ptr: *mut f32, nx_pts: u32, ny_pts: u32) -> i32 {
 
-    /*  Shift factor in the z axis for centering the mesh around the origin.  */
-    const HEIGH_SHIFT: f32 = -2.0;
+    /*  The right-most and top-most extremes of the surface.                  */
+    let x_end: f32 = PARABOLOID_X_START + PARABOLOID_WIDTH;
+    let y_end: f32 = PARABOLOID_Y_START + PARABOLOID_HEIGHT;
 
-    /*  Variable for indexing over the array being written to.                */
-    let mut index: usize = 0;
+    /*  The default domain is given by the constants in lib.rs.               */
+    return generate_mesh_domain(ptr, nx_pts, ny_pts, PARABOLOID_X_START,
+                                x_end, PARABOLOID_Y_START, y_end);
+}
+/*  End of generate_mesh.                                                     */
 
-    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
-     *  Check if the input sizes are too big.                                 */
-    if (nx_pts > MAX_WIDTH) || (ny_pts > MAX_HEIGHT) {
-        return;
+#[cfg(test)]
+mod tests {
+    use super::generate_mesh;
+    use crate::lock::lock;
+    use crate::{HEIGHT_SHIFT, TEST_LOCK};
+    use crate::{STATUS_DEGENERATE, STATUS_OK, STATUS_TOO_TALL, STATUS_TOO_WIDE};
+
+    /*  Value the buffers are filled with, to see which elements are written. */
+    const SENTINEL: f32 = 1234.5;
+
+    /*  The corners of the grid are (+/-1, +/-1) in the plane, with the       *
+     *  heights given by z = x^2 + 2y^2 plus the shift.                       */
+    #[test]
+    fn corners_match_the_formula() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let (nx_pts, ny_pts): (u32, u32) = (5, 4);
+        let mut arr: [f32; 60] = [SENTINEL; 60];
+
+        let status: i32 = generate_mesh(arr.as_mut_ptr(), nx_pts, ny_pts);
+        assert_eq!(status, STATUS_OK);
+
+        let shift: f32 = *lock(&HEIGHT_SHIFT);
+        let corners: [(usize, f32, f32); 4] = [
+            (0, -1.0, -1.0), (4, 1.0, -1.0), (15, -1.0, 1.0), (19, 1.0, 1.0)
+        ];
+
+        for (point, x, y) in corners {
+            let z: f32 = x * x + 2.0 * y * y + shift;
+            assert_eq!(arr[3 * point..3 * point + 3], [x, y, z]);
+        }
     }
 
-    /*  Loop through the vertical axis. The elliptic paraboloid lies          *
-     *  above the xy plane, meaning it is of the form z = f(x, y).            *
-     *                                                                        *
-     *  Note, since the y index is the outer for-loop, the array is indexed   *
-     *  in row-major fashion. That is, index = y * width + x.                 */
-    for y_index in 0..ny_pts  {
-
-        /*  Convert pixel index to y coordinate.                              */
-        let y_pt: f32 = PARABOLOID_Y_START + (y_index as f32) * dy;
+    /*  Exactly 3 nx ny elements are written, nothing after them.             */
+    #[test]
+    fn writes_three_floats_per_point() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let (nx_pts, ny_pts): (u32, u32) = (7, 3);
+        let len: usize = 3 * 7 * 3;
+        let mut arr: [f32; 80] = [SENTINEL; 80];
+
+        assert_eq!(generate_mesh(arr.as_mut_ptr(), nx_pts, ny_pts), STATUS_OK);
+        assert!(arr[..len].iter().all(|&value| value != SENTINEL));
+        assert!(arr[len..].iter().all(|&value| value == SENTINEL));
+    }
 
-        /*  Loop through the horizontal component of the object.              */
-        for x_index in 0..nx_pts {
+    /*  A wide 512 by 128 mesh writes exactly 3 * 512 * 128 elements.         */
+    #[test]
+    fn wide_grid_writes_three_floats_per_point() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let len: usize = 3 * 512 * 128;
+        let mut arr: Vec<f32> = vec![SENTINEL; len + 16];
 
-            /*  Convert pixel index to x coordinate in the plane.             */
-            let x_pt: f32 = PARABOLOID_X_START + (x_index as f32) * dx;
+        assert_eq!(generate_mesh(arr.as_mut_ptr(), 512, 128), STATUS_OK);
+        assert!(arr[..len].iter().all(|&value| value != SENTINEL));
+        assert!(arr[len..].iter().all(|&value| value == SENTINEL));
+    }
 
-            /*  The elliptic paraboloid has a simple formula: z = x^2 + 2y^2. *
-             *  We shift this slightly to center the surface on the screen.   */
-            let z_pt: f32 = x_pt * x_pt + 2.0 * y_pt * y_pt + HEIGH_SHIFT;
+    /*  Input that is too wide, too tall, or too small is rejected, and the   *
+     *  buffer is left alone.                                                 */
+    #[test]
+    fn rejects_oversize_and_degenerate_input() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let mut arr: [f32; 12] = [SENTINEL; 12];
+        let ptr: *mut f32 = arr.as_mut_ptr();
+
+        assert_eq!(generate_mesh(ptr, 513, 2), STATUS_TOO_WIDE);
+        assert_eq!(generate_mesh(ptr, 2, 513), STATUS_TOO_TALL);
+        assert_eq!(generate_mesh(ptr, 1, 2), STATUS_DEGENERATE);
+        assert_eq!(generate_mesh(ptr, 2, 0), STATUS_DEGENERATE);
+        assert!(arr.iter().all(|&value| value == SENTINEL));
+    }
 
-            /*  Add this point to our vertex array.                           */
-            arr[index] = x_pt;
-            arr[index + 1] = y_pt;
-            arr[index + 2] = z_pt;
+    /*  A huge request is turned down before any slice is created, instead of *
+     *  making a slice of 10^10 points over a small buffer.                   */
+    #[test]
+    fn rejects_huge_input() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let mut arr: [f32; 4] = [SENTINEL; 4];
+        let ptr: *mut f32 = arr.as_mut_ptr();
 
-            /*  Move on to the next point in the mesh. A point needs 3 f32's. */
-            index += 3;
-        }
-        /*  End of horizontal for-loop.                                       */
+        assert_eq!(generate_mesh(ptr, 100000, 100000), STATUS_TOO_WIDE);
+        assert!(arr.iter().all(|&value| value == SENTINEL));
     }
-    /*  End of vertical for-loop.                                             */
 }
-/*  End of generate_mesh.                                                     */
@@ -0,0 +1,352 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the mesh for the surface, adding points in the squares of the*
+ *      grid where the surface is sharply curved.                             *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Physical width and height of the surface (projection onto the xy plane).  */
+pub use crate::{PARABOLOID_WIDTH, PARABOLOID_HEIGHT};
+
+/*  Left-most and bottom-most extremes of the surface (projected to xy plane).*/
+pub use crate::{PARABOLOID_X_START, PARABOLOID_Y_START};
+
+/*  Room in the mesh and triangle index buffers.                              */
+use crate::buffer_capacity::{point_capacity, triangle_index_capacity};
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Global for the shift in the z axis, see set_height_shift.                 */
+use crate::HEIGHT_SHIFT;
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Functions for converting the points for y up, see set_up_axis.            */
+use crate::up_axis::{is_y_up, convert_up_axis};
+
+/*  Function for forgetting the wireframe cached by generateIndicesCached.    */
+use crate::invalidate_index_cache::invalidate_index_cache;
+
+/*  Computes the height of the surface, the same formula as generate_mesh.    */
+fn height(x_pt: f32, y_pt: f32, height_shift: f32) -> f32 {
+    return x_pt * x_pt + 2.0 * y_pt * y_pt + height_shift;
+}
+/*  End of height.                                                            */
+
+/*  Estimates the Gaussian curvature of the surface at (x, y) using centered  *
+ *  finite differences with step sizes hx and hy.                             */
+fn curvature(x_pt: f32, y_pt: f32, hx: f32, hy: f32) -> f32 {
+
+    /*  The height at the point and at its eight neighbors. The curvature     *
+     *  only depends on differences of heights, so the shift is left out.     */
+    let z: f32 = height(x_pt, y_pt, 0.0);
+    let z_e: f32 = height(x_pt + hx, y_pt, 0.0);
+    let z_w: f32 = height(x_pt - hx, y_pt, 0.0);
+    let z_n: f32 = height(x_pt, y_pt + hy, 0.0);
+    let z_s: f32 = height(x_pt, y_pt - hy, 0.0);
+    let z_ne: f32 = height(x_pt + hx, y_pt + hy, 0.0);
+    let z_nw: f32 = height(x_pt - hx, y_pt + hy, 0.0);
+    let z_se: f32 = height(x_pt + hx, y_pt - hy, 0.0);
+    let z_sw: f32 = height(x_pt - hx, y_pt - hy, 0.0);
+
+    /*  First and second partial derivatives.                                 */
+    let f_x: f32 = (z_e - z_w) / (2.0 * hx);
+    let f_y: f32 = (z_n - z_s) / (2.0 * hy);
+    let f_xx: f32 = (z_e - 2.0 * z + z_w) / (hx * hx);
+    let f_yy: f32 = (z_n - 2.0 * z + z_s) / (hy * hy);
+    let f_xy: f32 = (z_ne - z_nw - z_se + z_sw) / (4.0 * hx * hy);
+
+    /*  For a graph z = f(x, y) the Gaussian curvature is:                    *
+     *                                                                        *
+     *                  f_xx f_yy - f_xy^2                                    *
+     *        K = ---------------------------                                 *
+     *             (1 + f_x^2 + f_y^2)^2                                      *
+     *                                                                        *
+     *  The second derivatives alone are constant for the paraboloid. The     *
+     *  denominator makes K largest at the tip and small on the steep sides.  */
+    let grad_sq: f32 = 1.0 + f_x * f_x + f_y * f_y;
+    return (f_xx * f_yy - f_xy * f_xy) / (grad_sq * grad_sq);
+}
+/*  End of curvature.                                                         */
+
+/*  Writes the point on the surface above (x, y) to the array. The index is   *
+ *  the number of the point, not of its first float.                          */
+fn write_point(arr: &mut [f32], point: u32, x_pt: f32, y_pt: f32,
+               height_shift: f32, y_up: bool) {
+    let index: usize = 3 * (point as usize);
+    arr[index] = x_pt;
+    arr[index + 1] = y_pt;
+    arr[index + 2] = height(x_pt, y_pt, height_shift);
+    convert_up_axis(arr, index, y_up);
+}
+/*  End of write_point.                                                       */
+
+/*  Marks an edge or a square that has not been given an extra point.         */
+const NO_POINT: u32 = u32::MAX;
+
+/*  Function for generating the mesh with extra points where the absolute     *
+ *  value of the curvature exceeds the threshold, along with the triangles    *
+ *  for it. The first w h points are the usual grid, the same as              *
+ *  generate_mesh, where w is the width and h is the height, so               *
+ *  generate_indices still gives the wireframe for them. A square of the grid *
+ *  that is split gets the midpoints of its four edges and its center. An     *
+ *  edge shared by two split squares gets a single midpoint. A square that is *
+ *  not split, but has a split neighbor, also gets a center, and is fanned    *
+ *  out from it through the midpoints on its edges so that the surface has no *
+ *  cracks. All other squares are two triangles, as in                        *
+ *  generate_triangle_indices. The points are written to mesh_ptr, which      *
+ *  should be the mesh buffer, and the triangles, counter-clockwise when      *
+ *  viewed from above, to index_ptr, which should be the triangle index       *
+ *  buffer. Returns the number of points and of indices written, or zeros if  *
+ *  the input is invalid or the refined mesh does not fit in the buffers.     */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_mesh_adaptive(mesh_ptr: *mut f32, index_ptr: *mut u32,
+                              nx_pts: u32, ny_pts: u32,
+                              curvature_threshold: f32) -> [u32; 2] {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return [0, 0];
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(nx_pts, ny_pts) != STATUS_OK {
+        return [0, 0];
+    }
+
+    /*  Step sizes in the horizontal and vertical axes.                       */
+    let dx: f32 = PARABOLOID_WIDTH / ((nx_pts - 1) as f32);
+    let dy: f32 = PARABOLOID_HEIGHT / ((ny_pts - 1) as f32);
+
+    /*  The checks above keep these small, so usize arithmetic is safe.       */
+    let width: usize = nx_pts as usize;
+    let height: usize = ny_pts as usize;
+
+    /*  Decide which squares are split, estimating the curvature at the       *
+     *  center of each square. Half the grid spacing is used so that the      *
+     *  estimate stays inside the square. Squares are labeled row-major by    *
+     *  their bottom left corners, as in generate_triangle_indices.           */
+    let mut split: Vec<bool> = vec![false; (width - 1) * (height - 1)];
+
+    for y_index in 0..(height - 1) {
+        let y_mid: f32 = PARABOLOID_Y_START + ((y_index as f32) + 0.5) * dy;
+
+        for x_index in 0..(width - 1) {
+            let x_mid: f32 = PARABOLOID_X_START + ((x_index as f32) + 0.5) * dx;
+            let k: f32 = curvature(x_mid, y_mid, 0.5 * dx, 0.5 * dy);
+            let square: usize = y_index * (width - 1) + x_index;
+            split[square] = k.abs() > curvature_threshold;
+        }
+    }
+
+    /*  The horizontal edge (x, y) joins the points (x, y) and (x + 1, y),    *
+     *  and the vertical edge (x, y) joins (x, y) and (x, y + 1). These hold  *
+     *  the number of the midpoint of each edge, if it has one, and of the    *
+     *  center of each square. The new points come after the grid.            */
+    let mut horizontal: Vec<u32> = vec![NO_POINT; (width - 1) * height];
+    let mut vertical: Vec<u32> = vec![NO_POINT; width * (height - 1)];
+    let mut center: Vec<u32> = vec![NO_POINT; (width - 1) * (height - 1)];
+    let mut n_pts: u32 = nx_pts * ny_pts;
+
+    /*  Give each edge of a split square a midpoint, unless the neighboring   *
+     *  square already did.                                                   */
+    for y_index in 0..(height - 1) {
+        for x_index in 0..(width - 1) {
+
+            if !split[y_index * (width - 1) + x_index] {
+                continue;
+            }
+
+            /*  The bottom and top edges, then the left and right edges.      */
+            let bottom: usize = y_index * (width - 1) + x_index;
+            let left: usize = y_index * width + x_index;
+
+            for edge in [bottom, bottom + width - 1] {
+                if horizontal[edge] == NO_POINT {
+                    horizontal[edge] = n_pts;
+                    n_pts += 1;
+                }
+            }
+
+            for edge in [left, left + 1] {
+                if vertical[edge] == NO_POINT {
+                    vertical[edge] = n_pts;
+                    n_pts += 1;
+                }
+            }
+        }
+    }
+
+    /*  Any square with a midpoint on one of its edges gets a center, and is  *
+     *  fanned out from it. There is one triangle for each of its four        *
+     *  corners and for each midpoint. The other squares are two triangles.   */
+    let mut n_indices: usize = 0;
+
+    for y_index in 0..(height - 1) {
+        for x_index in 0..(width - 1) {
+
+            let square: usize = y_index * (width - 1) + x_index;
+            let left: usize = y_index * width + x_index;
+
+            let midpoints: usize = [
+                horizontal[square], vertical[left + 1],
+                horizontal[square + width - 1], vertical[left]
+            ].iter().filter(|&&point| point != NO_POINT).count();
+
+            if midpoints == 0 {
+                n_indices += 6;
+            } else {
+                center[square] = n_pts;
+                n_pts += 1;
+                n_indices += 3 * (4 + midpoints);
+            }
+        }
+    }
+
+    /*  Avoiding writing beyond the bounds of the arrays that were allocated. *
+     *  The refined mesh can have up to four times as many points and         *
+     *  triangles as the grid, so check the totals, not just the dimensions.  *
+     *  This is done before the slices are created.                           */
+    if (n_pts as usize) > point_capacity() {
+        return [0, 0];
+    }
+
+    if n_indices > triangle_index_capacity() {
+        return [0, 0];
+    }
+
+    /*  The array may hold the wireframe cached by generateIndicesCached.     */
+    invalidate_index_cache();
+
+    /*  Get slices for the data.                                              */
+    let n_floats: usize = 3 * (n_pts as usize);
+    let arr = unsafe { std::slice::from_raw_parts_mut(mesh_ptr, n_floats) };
+    let indices = unsafe {
+        std::slice::from_raw_parts_mut(index_ptr, n_indices)
+    };
+
+    /*  Shift factor in the z axis, read once rather than for every point.    */
+    let height_shift: f32 = *lock(&HEIGHT_SHIFT);
+
+    /*  Whether to convert the points for y up, see set_up_axis.              */
+    let y_up: bool = is_y_up();
+
+    /*  First the regular grid, in row-major order.                           */
+    for y_index in 0..ny_pts {
+
+        /*  Convert pixel index to y coordinate.                              */
+        let y_pt: f32 = PARABOLOID_Y_START + (y_index as f32) * dy;
+
+        for x_index in 0..nx_pts {
+
+            /*  Convert pixel index to x coordinate in the plane.             */
+            let x_pt: f32 = PARABOLOID_X_START + (x_index as f32) * dx;
+            write_point(arr, y_index * nx_pts + x_index, x_pt, y_pt,
+                        height_shift, y_up);
+        }
+        /*  End of horizontal for-loop.                                       */
+    }
+    /*  End of vertical for-loop.                                             */
+
+    /*  Then the midpoints of the edges.                                      */
+    for (edge, &point) in horizontal.iter().enumerate() {
+        if point != NO_POINT {
+            let x_pt: f32 = ((edge % (width - 1)) as f32 + 0.5) * dx;
+            let y_pt: f32 = ((edge / (width - 1)) as f32) * dy;
+            write_point(arr, point, PARABOLOID_X_START + x_pt,
+                        PARABOLOID_Y_START + y_pt, height_shift, y_up);
+        }
+    }
+
+    for (edge, &point) in vertical.iter().enumerate() {
+        if point != NO_POINT {
+            let x_pt: f32 = ((edge % width) as f32) * dx;
+            let y_pt: f32 = ((edge / width) as f32 + 0.5) * dy;
+            write_point(arr, point, PARABOLOID_X_START + x_pt,
+                        PARABOLOID_Y_START + y_pt, height_shift, y_up);
+        }
+    }
+
+    /*  Variable for indexing over the index array being written to.          */
+    let mut index: usize = 0;
+
+    /*  Finally the centers and the triangles, square by square.              */
+    for y_index in 0..(height - 1) {
+        for x_index in 0..(width - 1) {
+
+            let square: usize = y_index * (width - 1) + x_index;
+            let left: usize = y_index * width + x_index;
+
+            /*  The corners of the square, as in generate_triangle_indices.   */
+            let index00: u32 = left as u32;
+            let index01: u32 = index00 + 1;
+            let index10: u32 = index00 + nx_pts;
+            let index11: u32 = index10 + 1;
+
+            if center[square] == NO_POINT {
+                indices[index..index + 6].copy_from_slice(&[
+                    index00, index01, index11, index00, index11, index10
+                ]);
+
+                index += 6;
+                continue;
+            }
+
+            let x_pt: f32 = ((x_index as f32) + 0.5) * dx;
+            let y_pt: f32 = ((y_index as f32) + 0.5) * dy;
+            write_point(arr, center[square], PARABOLOID_X_START + x_pt,
+                        PARABOLOID_Y_START + y_pt, height_shift, y_up);
+
+            /*  Walk around the boundary of the square counter-clockwise,     *
+             *  including the midpoints that are there, and join each pair of *
+             *  neighboring boundary points to the center.                    */
+            let boundary: [u32; 8] = [
+                index00, horizontal[square], index01, vertical[left + 1],
+                index11, horizontal[square + width - 1], index10, vertical[left]
+            ];
+
+            let mut previous: u32 = index00;
+
+            for &point in boundary.iter().cycle().skip(1).take(8) {
+
+                if point == NO_POINT {
+                    continue;
+                }
+
+                indices[index] = center[square];
+                indices[index + 1] = previous;
+                indices[index + 2] = point;
+                index += 3;
+                previous = point;
+            }
+        }
+    }
+
+    return [n_pts, n_indices as u32];
+}
+/*  End of generate_mesh_adaptive.                                            */
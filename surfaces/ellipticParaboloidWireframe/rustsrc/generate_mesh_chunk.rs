@@ -0,0 +1,139 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes some of the rows of the mesh, so generation can be spread    *
+ *      over several frames.                                                  *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Physical width and height of the surface (projection onto the xy plane).  */
+pub use crate::{PARABOLOID_WIDTH, PARABOLOID_HEIGHT};
+
+/*  Left-most and bottom-most extremes of the surface (projected to xy plane).*/
+pub use crate::{PARABOLOID_X_START, PARABOLOID_Y_START};
+
+/*  Number of elements in the vertex array for the given dimensions.          */
+use crate::vertex_float_count::vertex_float_count;
+
+/*  Shift in the z axis, set by set_height_shift.                             */
+use crate::HEIGHT_SHIFT;
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  Conversion from z up to the axis chosen with set_up_axis.                 */
+use crate::up_axis::{is_y_up, to_up_axis};
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Function for computing the rows row_start up to, but not including,       *
+ *  row_start + row_count of the mesh from generate_mesh. ptr is the start of *
+ *  the whole array, not of the chunk. A 512 x 512 mesh in a single call can  *
+ *  stall the browser for a frame, so JavaScript may instead call this once   *
+ *  per frame, passing the return value as the next row_start. Returns the    *
+ *  row after the last one written, which is ny_pts once the mesh is          *
+ *  complete. Nothing is written, and ny_pts is returned, if the input is     *
+ *  invalid.                                                                  */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_mesh_chunk(ptr: *mut f32, nx_pts: u32, ny_pts: u32,
+                           row_start: u32, row_count: u32) -> u32 {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return ny_pts;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(nx_pts, ny_pts) != STATUS_OK {
+        return ny_pts;
+    }
+
+    /*  Nothing is left to do if we are already past the top row. Otherwise   *
+     *  stop at the top row, however big row_count is.                        */
+    if row_start >= ny_pts {
+        return ny_pts;
+    }
+
+    let row_end: u32 = row_start.saturating_add(row_count).min(ny_pts);
+
+    /*  The array is the size of the whole mesh.                              */
+    let len: usize = vertex_float_count(nx_pts, ny_pts) as usize;
+
+    /*  Get a slice for the data.                                             */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  The values must match generate_mesh exactly, so that a mesh built in  *
+     *  chunks is identical to one built all at once. The arithmetic below is *
+     *  the same as in generate_mesh_domain, in double precision, for the     *
+     *  default domain.                                                       */
+    let x0: f64 = PARABOLOID_X_START as f64;
+    let y0: f64 = PARABOLOID_Y_START as f64;
+    let x1: f64 = (PARABOLOID_X_START + PARABOLOID_WIDTH) as f64;
+    let y1: f64 = (PARABOLOID_Y_START + PARABOLOID_HEIGHT) as f64;
+    let dx: f64 = (x1 - x0) / ((nx_pts - 1) as f64);
+    let dy: f64 = (y1 - y0) / ((ny_pts - 1) as f64);
+
+    /*  Shift factor in the z axis for centering the mesh around the origin.  */
+    let height_shift: f64 = *lock(&HEIGHT_SHIFT) as f64;
+
+    /*  Whether to convert the points for y up, see set_up_axis.              */
+    let y_up: bool = is_y_up();
+
+    /*  The first element of the first row in the chunk. The array is         *
+     *  row-major, index = y * width + x, and each point is 3 f32's.          */
+    let mut index: usize = (3 * row_start * nx_pts) as usize;
+
+    for y_index in row_start..row_end {
+
+        /*  Convert pixel index to y coordinate.                              */
+        let y_pt: f64 = y0 + (y_index as f64) * dy;
+
+        /*  Loop through the horizontal component of the object.              */
+        for x_index in 0..nx_pts {
+
+            /*  Convert pixel index to x coordinate in the plane.             */
+            let x_pt: f64 = x0 + (x_index as f64) * dx;
+
+            /*  The elliptic paraboloid, z = x^2 + 2y^2, shifted down.        */
+            let z_pt: f64 = x_pt * x_pt + 2.0 * y_pt * y_pt + height_shift;
+
+            /*  Add this point to our vertex array.                           */
+            let point: [f32; 3] = [x_pt as f32, y_pt as f32, z_pt as f32];
+            let [px, py, pz]: [f32; 3] = to_up_axis(point, y_up);
+            arr[index] = px;
+            arr[index + 1] = py;
+            arr[index + 2] = pz;
+
+            /*  Move on to the next point in the mesh. A point needs 3 f32's. */
+            index += 3;
+        }
+        /*  End of horizontal for-loop.                                       */
+    }
+    /*  End of vertical for-loop.                                             */
+
+    return row_end;
+}
+/*  End of generate_mesh_chunk.                                               */
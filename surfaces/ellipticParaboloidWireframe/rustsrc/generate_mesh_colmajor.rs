@@ -0,0 +1,125 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the locations of the points in the mesh in column-major      *
+ *      order.                                                                *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Physical width and height of the surface (projection onto the xy plane).  */
+pub use crate::{PARABOLOID_WIDTH, PARABOLOID_HEIGHT};
+
+/*  Left-most and bottom-most extremes of the surface (projected to xy plane).*/
+pub use crate::{PARABOLOID_X_START, PARABOLOID_Y_START};
+
+/*  Status codes returned to JavaScript, zero means success.                  */
+use crate::{STATUS_OK, STATUS_DEGENERATE};
+
+/*  Number of elements in the vertex array for the given dimensions.          */
+use crate::vertex_float_count::vertex_float_count;
+
+/*  Shift in the z axis, set by set_height_shift.                             */
+use crate::HEIGHT_SHIFT;
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  Conversion from z up to the axis chosen with set_up_axis.                 */
+use crate::up_axis::{is_y_up, to_up_axis};
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Function for generating the same vertices as generate_mesh, but with the  *
+ *  loops swapped so that index = x * height + y. The indices must come from  *
+ *  generate_indices_colmajor, generate_indices only works with               *
+ *  generate_mesh. Returns one of the status codes in lib.rs.                 */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_mesh_colmajor(ptr: *mut f32, nx_pts: u32, ny_pts: u32) -> i32 {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return STATUS_DEGENERATE;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    let status: i32 = capacity_status(nx_pts, ny_pts);
+
+    if status != STATUS_OK {
+        return status;
+    }
+
+    /*  The size of the array is the same as it is for row-major order.       */
+    let len: usize = vertex_float_count(nx_pts, ny_pts) as usize;
+
+    /*  Get a slice for the data.                                             */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Step sizes in the horizontal and vertical axes. As in                 *
+     *  generate_mesh_domain, the coordinates are computed in double          *
+     *  precision.                                                            */
+    let dx: f64 = (PARABOLOID_WIDTH as f64) / ((nx_pts - 1) as f64);
+    let dy: f64 = (PARABOLOID_HEIGHT as f64) / ((ny_pts - 1) as f64);
+
+    /*  Shift factor in the z axis for centering the mesh around the origin.  *
+     *  This is -2 unless it has been changed with set_height_shift.          */
+    let height_shift: f64 = *lock(&HEIGHT_SHIFT) as f64;
+
+    /*  Whether to convert the points for y up, see set_up_axis.              */
+    let y_up: bool = is_y_up();
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  The x index is now the outer for-loop, so the array is indexed in     *
+     *  column-major fashion. That is, index = x * height + y.                */
+    for x_index in 0..nx_pts {
+
+        /*  Convert pixel index to x coordinate in the plane.                 */
+        let x_pt: f64 = (PARABOLOID_X_START as f64) + (x_index as f64) * dx;
+
+        /*  Loop through the vertical component of the object.                */
+        for y_index in 0..ny_pts {
+
+            /*  Convert pixel index to y coordinate.                          */
+            let y_pt: f64 = (PARABOLOID_Y_START as f64) + (y_index as f64) * dy;
+
+            /*  Same formula as generate_mesh, z = x^2 + 2y^2, shifted down.  */
+            let z_pt: f64 = x_pt * x_pt + 2.0 * y_pt * y_pt + height_shift;
+
+            /*  Add this point to our vertex array.                           */
+            let point: [f32; 3] = [x_pt as f32, y_pt as f32, z_pt as f32];
+            let [px, py, pz]: [f32; 3] = to_up_axis(point, y_up);
+            arr[index] = px;
+            arr[index + 1] = py;
+            arr[index + 2] = pz;
+
+            /*  Move on to the next point in the mesh. A point needs 3 f32's. */
+            index += 3;
+        }
+        /*  End of vertical for-loop.                                         */
+    }
+    /*  End of horizontal for-loop.                                           */
+
+    return STATUS_OK;
+}
+/*  End of generate_mesh_colmajor.                                            */
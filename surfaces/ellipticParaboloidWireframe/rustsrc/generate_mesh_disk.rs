@@ -0,0 +1,131 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Generates the saddle z = a x^2 + b y^2 over the unit disk, sampled in *
+ *      polar coordinates.                                                    *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks against the current capacity of the buffers.                       */
+use crate::buffer_capacity::{capacity_status, point_capacity};
+
+/*  The angle runs over [0, 2 pi).                                            */
+use std::f32::consts::PI;
+
+/*  Functions for converting the points for y up, see set_up_axis.            */
+use crate::up_axis::{is_y_up, convert_up_axis};
+
+/*  Function for generating the mesh for z = a x^2 + b y^2 over the unit      *
+ *  disk, the shape of a potato chip when a and b have opposite signs.        *
+ *  Sampling on a square leaves sharp corners, so this uses polar coordinates *
+ *  instead. The point for (r, theta) is:                                     *
+ *                                                                            *
+ *          x = r cos(theta)                                                  *
+ *          y = r sin(theta)                                                  *
+ *          z = a r^2 cos^2(theta) + b r^2 sin^2(theta)                       *
+ *                                                                            *
+ *  with theta the horizontal axis and r the vertical one. The rings have     *
+ *  radii 1 / n_r, 2 / n_r, ..., 1, and the center of the disk is added as    *
+ *  one more point after the grid, so that the origin is not repeated once    *
+ *  for every angle. Use generate_indices_disk for the wireframe. Returns the *
+ *  number of vertices written, or zero if the input is invalid.              */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_mesh_disk(ptr: *mut f32, n_r: u32, n_theta: u32,
+                          a: f32, b: f32) -> u32 {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero, so return early.                                */
+    if (n_r < 2) || (n_theta < 2) {
+        return 0;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(n_theta, n_r) != STATUS_OK {
+        return 0;
+    }
+
+    /*  The rings, plus the center of the disk.                               */
+    let n_pts: u32 = n_theta * n_r + 1;
+
+    /*  The center takes one more point than the grid, so a grid that fills   *
+     *  the mesh buffer leaves no room for it. Check the total.               */
+    if (n_pts as usize) > point_capacity() {
+        return 0;
+    }
+
+    /*  Get a slice for the data. Each point needs three f32's.               */
+    let len: usize = 3 * (n_pts as usize);
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  The seam is closed by the indices, so the angle stops one step short  *
+     *  of 2 pi. Otherwise the first and last columns would coincide.         */
+    let dtheta: f32 = 2.0 * PI / (n_theta as f32);
+    let dr: f32 = 1.0 / (n_r as f32);
+
+    /*  Whether to convert the points for y up, see set_up_axis.              */
+    let y_up: bool = is_y_up();
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Row-major, same as the other surfaces, with index = r * width + theta.*/
+    for r_index in 0..n_r {
+
+        /*  The radius of the current ring, the innermost ring is one step    *
+         *  out.                                                              */
+        let r_val: f32 = ((r_index + 1) as f32) * dr;
+        let r_sq: f32 = r_val * r_val;
+
+        for theta_index in 0..n_theta {
+
+            /*  The direction from the center of the disk.                    */
+            let theta: f32 = (theta_index as f32) * dtheta;
+            let cos_theta: f32 = theta.cos();
+            let sin_theta: f32 = theta.sin();
+
+            /*  The quadratic form only depends on the angle and r^2.         */
+            let cos_sq: f32 = cos_theta * cos_theta;
+            let sin_sq: f32 = sin_theta * sin_theta;
+            let form: f32 = a * cos_sq + b * sin_sq;
+
+            arr[index] = r_val * cos_theta;
+            arr[index + 1] = r_val * sin_theta;
+            arr[index + 2] = r_sq * form;
+            convert_up_axis(arr, index, y_up);
+
+            /*  Move on to the next point in the mesh. A point needs 3 f32's. */
+            index += 3;
+        }
+        /*  End of theta for-loop.                                            */
+    }
+    /*  End of r for-loop.                                                    */
+
+    /*  The center of the disk, where every term vanishes.                    */
+    arr[index] = 0.0;
+    arr[index + 1] = 0.0;
+    arr[index + 2] = 0.0;
+
+    return n_pts;
+}
+/*  End of generate_mesh_disk.                                                */
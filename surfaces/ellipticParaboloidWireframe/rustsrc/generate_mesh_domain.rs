@@ -0,0 +1,140 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the points in the mesh for the surface over a given domain.  *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Status codes returned to JavaScript, zero means success.                  */
+use crate::{STATUS_OK, STATUS_TOO_LARGE, STATUS_DEGENERATE};
+
+/*  Number of f32's in a single vertex, the x, y, and z coordinates.          */
+use crate::VERTEX_STRIDE;
+
+/*  Shift in the z axis, set by set_height_shift.                             */
+use crate::HEIGHT_SHIFT;
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  Conversion from z up to the axis chosen with set_up_axis.                 */
+use crate::up_axis::{is_y_up, to_up_axis};
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Function for generating the mesh for the surface by calculating vertices. *
+ *  The domain of the surface is the rectangle [x0, x1] x [y0, y1]. Returns   *
+ *  one of the status codes in lib.rs, and nothing is written on failure.     */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_mesh_domain(ptr: *mut f32, nx_pts: u32, ny_pts: u32,
+                            x0: f32, x1: f32, y0: f32, y1: f32) -> i32 {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return STATUS_DEGENERATE;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big. This is done before the slice   *
+     *  is created, since a slice longer than the allocation is undefined     *
+     *  behavior even if the extra elements are never touched.                */
+    let status: i32 = capacity_status(nx_pts, ny_pts);
+
+    if status != STATUS_OK {
+        return status;
+    }
+
+    /*  The size of the array passed to us is given by the number of points   *
+     *  in the mesh, which is simply the width times the height. The check    *
+     *  above keeps this small, but the product is computed with checked      *
+     *  arithmetic anyway. A wrapped length would give a slice that is too    *
+     *  short, and this stays safe even if MAX_WIDTH or MAX_HEIGHT are raised.*/
+    let len: usize = match nx_pts.checked_mul(ny_pts)
+        .and_then(|n| n.checked_mul(VERTEX_STRIDE)) {
+        Some(n) => n as usize,
+        None => return STATUS_TOO_LARGE,
+    };
+
+    /*  Get a slice for the data.                                             */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Step sizes in the horizontal and vertical axes. The coordinates are   *
+     *  computed in double precision and only converted to single precision   *
+     *  when they are stored. At large resolutions the rounding error in      *
+     *  single precision makes the grid visibly non-uniform near the edges.   */
+    let dx: f64 = ((x1 as f64) - (x0 as f64)) / ((nx_pts - 1) as f64);
+    let dy: f64 = ((y1 as f64) - (y0 as f64)) / ((ny_pts - 1) as f64);
+
+    /*  Shift factor in the z axis for centering the mesh around the origin.  *
+     *  This is -2 unless it has been changed with set_height_shift.          */
+    let height_shift: f64 = *lock(&HEIGHT_SHIFT) as f64;
+
+    /*  Whether to convert the points for y up, see set_up_axis.              */
+    let y_up: bool = is_y_up();
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  An empty interval gives a zero step size, and the surface collapses   *
+     *  to a line. Treat this as invalid input and return early.              */
+    if (x1 == x0) || (y1 == y0) {
+        return STATUS_DEGENERATE;
+    }
+
+    /*  Loop through the vertical axis. The elliptic paraboloid lies          *
+     *  above the xy plane, meaning it is of the form z = f(x, y).            *
+     *                                                                        *
+     *  Note, since the y index is the outer for-loop, the array is indexed   *
+     *  in row-major fashion. That is, index = y * width + x.                 */
+    for y_index in 0..ny_pts  {
+
+        /*  Convert pixel index to y coordinate.                              */
+        let y_pt: f64 = (y0 as f64) + (y_index as f64) * dy;
+
+        /*  Loop through the horizontal component of the object.              */
+        for x_index in 0..nx_pts {
+
+            /*  Convert pixel index to x coordinate in the plane.             */
+            let x_pt: f64 = (x0 as f64) + (x_index as f64) * dx;
+
+            /*  The elliptic paraboloid has a simple formula: z = x^2 + 2y^2. *
+             *  We shift this slightly to center the surface on the screen.   */
+            let z_pt: f64 = x_pt * x_pt + 2.0 * y_pt * y_pt + height_shift;
+
+            /*  Add this point to our vertex array, converting to single      *
+             *  precision only now that the computation is complete.          */
+            let point: [f32; 3] = [x_pt as f32, y_pt as f32, z_pt as f32];
+            let [px, py, pz]: [f32; 3] = to_up_axis(point, y_up);
+            arr[index] = px;
+            arr[index + 1] = py;
+            arr[index + 2] = pz;
+
+            /*  Move on to the next point in the mesh. A point needs 3 f32's. */
+            index += 3;
+        }
+        /*  End of horizontal for-loop.                                       */
+    }
+    /*  End of vertical for-loop.                                             */
+
+    return STATUS_OK;
+}
+/*  End of generate_mesh_domain.                                              */
@@ -0,0 +1,104 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the locations of the points in the mesh for any Surface.     *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Trait for surfaces that can be sampled over a rectangle.                  */
+use crate::surface::Surface;
+
+/*  Number of elements in the vertex array for the given dimensions.          */
+use crate::vertex_float_count::vertex_float_count;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Functions for converting the points for y up, see set_up_axis.            */
+use crate::up_axis::{is_y_up, to_up_axis};
+
+/*  Function for generating the mesh for an arbitrary surface. The grid,      *
+ *  layout, and checks are the same as generate_mesh.                         */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_mesh_generic<S: Surface>(ptr: *mut f32, nx_pts: u32,
+                                         ny_pts: u32, surface: &S) {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(nx_pts, ny_pts) != STATUS_OK {
+        return;
+    }
+
+    /*  The size of the array passed to us is given by the number of points   *
+     *  in the mesh, which is simply the width times the height.              */
+    let len: usize = vertex_float_count(nx_pts, ny_pts) as usize;
+
+    /*  Get a slice for the data.                                             */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  The rectangle the surface is defined on.                              */
+    let (x0, x1): (f32, f32) = surface.x_range();
+    let (y0, y1): (f32, f32) = surface.y_range();
+
+    /*  Step sizes in the horizontal and vertical axes.                       */
+    let dx: f32 = (x1 - x0) / ((nx_pts - 1) as f32);
+    let dy: f32 = (y1 - y0) / ((ny_pts - 1) as f32);
+
+    /*  Whether to convert the points for y up, see set_up_axis.              */
+    let y_up: bool = is_y_up();
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Loop through the vertical axis. Since the y index is the outer        *
+     *  for-loop, the array is indexed in row-major fashion. That is, index = *
+     *  y * width + x.                                                        */
+    for y_index in 0..ny_pts {
+
+        /*  Convert pixel index to y coordinate.                              */
+        let y_pt: f32 = y0 + (y_index as f32) * dy;
+
+        /*  Loop through the horizontal component of the object.              */
+        for x_index in 0..nx_pts {
+
+            /*  Convert pixel index to x coordinate in the plane.             */
+            let x_pt: f32 = x0 + (x_index as f32) * dx;
+
+            /*  Add this point to our vertex array.                           */
+            let point: [f32; 3] = surface.eval(x_pt, y_pt);
+            arr[index..index + 3].copy_from_slice(&to_up_axis(point, y_up));
+
+            /*  Move on to the next point in the mesh. A point needs 3 f32's. */
+            index += 3;
+        }
+        /*  End of horizontal for-loop.                                       */
+    }
+    /*  End of vertical for-loop.                                             */
+}
+/*  End of generate_mesh_generic.                                             */
@@ -0,0 +1,123 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes several copies of the mesh side by side in a single vertex   *
+ *      array.                                                                *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Status code for success, returned by generate_mesh.                       */
+use crate::STATUS_OK;
+
+/*  Function for computing the points on the surface.                         */
+use crate::generate_mesh::generate_mesh;
+
+/*  Number of elements in the vertex array for the given dimensions.          */
+use crate::vertex_float_count::vertex_float_count;
+
+/*  Number of points the mesh buffer currently has room for.                  */
+use crate::buffer_capacity::point_capacity;
+
+/*  Functions for converting the shifts for y up, see set_up_axis.            */
+use crate::up_axis::{is_y_up, to_up_axis};
+
+/*  Function for generating cols * rows copies of the mesh, each shifted in   *
+ *  the xy plane so that the copies sit spacing apart, with the whole grid    *
+ *  centered on the origin. Tile (col, row) starts at vertex number (row *    *
+ *  cols + col) * nx_pts * ny_pts. Drawing all of them with the indices from  *
+ *  generate_indices_grid takes a single draw call. Returns the total number  *
+ *  of vertices, or zero if the input is invalid.                             */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_mesh_grid(ptr: *mut f32, nx_pts: u32, ny_pts: u32,
+                          cols: u32, rows: u32, spacing: f32) -> u32 {
+
+    /*  There must be at least one tile.                                      */
+    if (cols == 0) || (rows == 0) {
+        return 0;
+    }
+
+    /*  A grid needs at least two points along each axis. Otherwise the       *
+     *  length of the array underflows, so return early.                      */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return 0;
+    }
+
+    /*  The vertex numbers must fit in a u32, and so must the array length.   */
+    let total: u32 = match cols.checked_mul(rows)
+        .and_then(|n| n.checked_mul(nx_pts))
+        .and_then(|n| n.checked_mul(ny_pts))
+        .and_then(|n| n.checked_mul(3)) {
+        Some(n) => n / 3,
+        None => return 0,
+    };
+
+    /*  generate_mesh checks that a single tile fits in the mesh buffer, but  *
+     *  all of the tiles together must fit as well.                           */
+    if (total as usize) > point_capacity() {
+        return 0;
+    }
+
+    /*  Each tile has its own block of the array, one mesh in size.           */
+    let tile_len: usize = vertex_float_count(nx_pts, ny_pts) as usize;
+
+    /*  Shifts for centering the grid of tiles on the origin.                 */
+    let x_center: f32 = 0.5 * ((cols - 1) as f32) * spacing;
+    let y_center: f32 = 0.5 * ((rows - 1) as f32) * spacing;
+
+    /*  Whether the tiles were generated for y up, see set_up_axis.           */
+    let y_up: bool = is_y_up();
+
+    for row in 0..rows {
+        for col in 0..cols {
+
+            /*  The first element of this tile in the array.                  */
+            let start: usize = ((row * cols + col) as usize) * tile_len;
+            let tile_ptr: *mut f32 = unsafe { ptr.add(start) };
+
+            /*  generate_mesh does all of the checks on nx_pts and ny_pts. If *
+             *  it fails for one tile, it fails for all of them.              */
+            if generate_mesh(tile_ptr, nx_pts, ny_pts) != STATUS_OK {
+                return 0;
+            }
+
+            /*  Move the tile into place.                                     */
+            let x_shift: f32 = (col as f32) * spacing - x_center;
+            let y_shift: f32 = (row as f32) * spacing - y_center;
+            let tile = unsafe {
+                std::slice::from_raw_parts_mut(tile_ptr, tile_len)
+            };
+
+            /*  The shift is in the plane the surface is drawn above, which   *
+             *  is the xz plane for y up.                                     */
+            let shift: [f32; 3] = to_up_axis([x_shift, y_shift, 0.0], y_up);
+
+            for point in tile.chunks_exact_mut(3) {
+                point[0] += shift[0];
+                point[1] += shift[1];
+                point[2] += shift[2];
+            }
+        }
+        /*  End of horizontal for-loop.                                       */
+    }
+    /*  End of vertical for-loop.                                             */
+
+    return total;
+}
+/*  End of generate_mesh_grid.                                                */
@@ -0,0 +1,109 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the locations of the points in the mesh and stores them in   *
+ *      the static mesh buffer.                                               *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  The two buffers for the mesh, and which of the two is in front.           */
+use crate::{MESH_BUFFER, MESH_BUFFER_BACK, MESH_BUFFERS_SWAPPED};
+
+/*  Used for reading the flag set by swap_mesh_buffers.                       */
+use std::sync::atomic::Ordering;
+
+/*  Locks one of the globals, giving up if it is already in use.              */
+use crate::lock::try_lock;
+
+/*  The current capacity of the buffers, and allocation on first use.         */
+use crate::buffer_capacity::{buffer_capacity, reserve_buffer};
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Number of elements in the vertex array for the given dimensions.          */
+use crate::vertex_float_count::vertex_float_count;
+
+/*  Function for computing the points on the surface.                         */
+use crate::generate_mesh::generate_mesh;
+
+/*  Function for generating the mesh directly into the front mesh buffer,     *
+ *  the one returned by get_mesh_buffer.                                      */
+pub fn generate_mesh_into_static(nx_pts: u32, ny_pts: u32) {
+
+    /*  generate_mesh checks the input sizes against the current capacity as  *
+     *  well, but check before the buffer is locked and allocated.            */
+    if capacity_status(nx_pts, ny_pts) != STATUS_OK {
+        return;
+    }
+
+    /*  The dimensions the buffer is allocated for.                           */
+    let (width, height): (u32, u32) = buffer_capacity();
+
+    /*  The front buffer is MESH_BUFFER unless the buffers have been swapped, *
+     *  the same choice as get_mesh_buffer.                                   */
+    let front = if MESH_BUFFERS_SWAPPED.load(Ordering::Acquire) {
+        &MESH_BUFFER_BACK
+    } else {
+        &MESH_BUFFER
+    };
+
+    /*  If the buffer is already locked, waiting on it would block forever    *
+     *  since wasm is single-threaded. Use try_lock and give up instead.      */
+    let mut buffer = match try_lock(front) {
+        Some(guard) => guard,
+        None => return,
+    };
+
+    /*  The buffer starts out empty, allocate it if this is the first use.    */
+    reserve_buffer(&mut buffer, vertex_float_count(width, height) as usize);
+    generate_mesh(buffer.as_mut_ptr(), nx_pts, ny_pts);
+}
+/*  End of generate_mesh_into_static.                                         */
+
+#[cfg(test)]
+mod tests {
+    use super::generate_mesh_into_static;
+    use crate::generate_mesh::generate_mesh;
+    use crate::get_mesh_buffer::get_mesh_buffer;
+    use crate::swap_mesh_buffers::swap_mesh_buffers;
+    use crate::{STATUS_OK, TEST_LOCK};
+
+    /*  After a swap the mesh is written to the new front buffer, which is    *
+     *  the one JavaScript reads through get_mesh_buffer.                     */
+    #[test]
+    fn writes_the_front_buffer_after_a_swap() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let mut expected: [f32; 18] = [0.0; 18];
+        assert_eq!(generate_mesh(expected.as_mut_ptr(), 3, 2), STATUS_OK);
+
+        swap_mesh_buffers();
+        generate_mesh_into_static(3, 2);
+
+        let ptr: *const f32 = get_mesh_buffer() as *const f32;
+        let front: &[f32] = unsafe { std::slice::from_raw_parts(ptr, 18) };
+        let matches: bool = front == expected;
+        swap_mesh_buffers();
+
+        assert!(matches);
+    }
+}
@@ -0,0 +1,140 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the vertices and the normals of the mesh, interleaved in a   *
+ *      single array.                                                         *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Physical width and height of the surface (projection onto the xy plane).  */
+pub use crate::{PARABOLOID_WIDTH, PARABOLOID_HEIGHT};
+
+/*  Left-most and bottom-most extremes of the surface (projected to xy plane).*/
+pub use crate::{PARABOLOID_X_START, PARABOLOID_Y_START};
+
+/*  Reciprocal square root, approximate with the fast-math feature.           */
+use crate::fast_rsqrt::fast_rsqrt;
+
+/*  Computes the length of the buffer, checking for overflow.                 */
+use crate::checked_len::checked_len;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Global for the shift in the z axis, see set_height_shift.                 */
+use crate::HEIGHT_SHIFT;
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Functions for converting the points for y up, see set_up_axis.            */
+use crate::up_axis::{is_y_up, convert_up_axis};
+
+/*  Function for generating the vertices and normals of the surface at once.  *
+ *  Each point is stored as six f32's, (x, y, z, nx, ny, nz), so the stride   *
+ *  is 6 floats (24 bytes). In three.js, wrap the buffer in an                *
+ *  InterleavedBuffer with stride 6, and create the position attribute with   *
+ *  offset 0 and the normal attribute with offset 3, both of item size 3.     *
+ *  The array needs 6wh f32's, twice what the mesh buffer holds, so it must   *
+ *  be allocated by the caller.                                               */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_mesh_with_normals(ptr: *mut f32, nx_pts: u32, ny_pts: u32) {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(nx_pts, ny_pts) != STATUS_OK {
+        return;
+    }
+
+    /*  Each point in the mesh needs three f32's for the vertex and three     *
+     *  more for the normal vector. The product is checked for overflow.      */
+    let len: usize = match checked_len(nx_pts, ny_pts, 6) {
+        Some(len) => len,
+        None => return,
+    };
+
+    /*  Get a slice for the data.                                             */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Step sizes in the horizontal and vertical axes.                       */
+    let dx: f32 = PARABOLOID_WIDTH / ((nx_pts - 1) as f32);
+    let dy: f32 = PARABOLOID_HEIGHT / ((ny_pts - 1) as f32);
+
+    /*  Shift factor in the z axis, read once rather than for every point.    */
+    let height_shift: f32 = *lock(&HEIGHT_SHIFT);
+
+    /*  Whether to convert the points for y up, see set_up_axis.              */
+    let y_up: bool = is_y_up();
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Loop through the points in the same row-major order as generate_mesh. *
+     *  That is, the i^th point starts at index 6 * (y * width + x).          */
+    for y_index in 0..ny_pts {
+
+        /*  Convert pixel index to y coordinate.                              */
+        let y_pt: f32 = PARABOLOID_Y_START + (y_index as f32) * dy;
+
+        /*  Loop through the horizontal component of the object.              */
+        for x_index in 0..nx_pts {
+
+            /*  Convert pixel index to x coordinate in the plane.             */
+            let x_pt: f32 = PARABOLOID_X_START + (x_index as f32) * dx;
+
+            /*  The elliptic paraboloid is z = x^2 + 2y^2, shifted slightly   *
+             *  to center the surface on the screen.                          */
+            let z_pt: f32 = x_pt * x_pt + 2.0 * y_pt * y_pt + height_shift;
+
+            /*  The vector (-f_x, -f_y, 1) is normal to the graph z = f(x,    *
+             *  y). For the paraboloid this is (-2x, -4y, 1).                 */
+            let nx: f32 = -2.0 * x_pt;
+            let ny: f32 = -4.0 * y_pt;
+
+            /*  The z component is 1, so the norm is never zero.              */
+            let rcpr_norm: f32 = fast_rsqrt(nx * nx + ny * ny + 1.0);
+
+            /*  Add the vertex to the array, followed by the unit normal.     */
+            arr[index] = x_pt;
+            arr[index + 1] = y_pt;
+            arr[index + 2] = z_pt;
+            convert_up_axis(arr, index, y_up);
+            arr[index + 3] = nx * rcpr_norm;
+            arr[index + 4] = ny * rcpr_norm;
+            arr[index + 5] = rcpr_norm;
+            convert_up_axis(arr, index + 3, y_up);
+
+            /*  Move on to the next point in the mesh. A point needs 6 f32's. */
+            index += 6;
+        }
+        /*  End of horizontal for-loop.                                       */
+    }
+    /*  End of vertical for-loop.                                             */
+}
+/*  End of generate_mesh_with_normals.                                        */
@@ -0,0 +1,122 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the locations of the points in the mesh for a surface of     *
+ *      revolution.                                                           *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  The angle runs over [0, 2 pi).                                            */
+use std::f32::consts::PI;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Functions for converting the points for y up, see set_up_axis.            */
+use crate::up_axis::{is_y_up, convert_up_axis};
+
+/*  Function for generating the mesh for the surface swept out by revolving a *
+ *  curve in the rz half-plane around the z axis. The curve is given by       *
+ *  profile_ptr, n_profile (r, z) pairs joined by straight lines, and is      *
+ *  sampled at n_s evenly spaced values of the parameter, the first and last  *
+ *  samples being the ends of the curve. The point for (theta, s) is (r(s)    *
+ *  cos(theta), r(s) sin(theta), z(s)). Spheres, cones, vases, and bells are  *
+ *  all of this form. The angle omits 2 pi, so the wireframe is               *
+ *  generate_indices_seam(ptr, n_theta, n_s, true, false).                    */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_revolution(ptr: *mut f32, n_theta: u32, n_s: u32,
+                           profile_ptr: *const f32, n_profile: u32) {
+
+    /*  A grid needs at least two points along each axis, and the curve needs *
+     *  at least two points to interpolate between.                           */
+    if (n_theta < 2) || (n_s < 2) || (n_profile < 2) {
+        return;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  The angle plays the role of the horizontal axis. Check the sizes.     */
+    if capacity_status(n_theta, n_s) != STATUS_OK {
+        return;
+    }
+
+    /*  The size of the array passed to us is given by the number of points   *
+     *  in the mesh, which is simply n_theta times n_s. Each point is 3       *
+     *  f32's.                                                                */
+    let len: usize = (3 * n_theta * n_s) as usize;
+
+    /*  Get slices for the data and for the curve, which is 2 f32's a point.  */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+    let profile = unsafe {
+        std::slice::from_raw_parts(profile_ptr, (2 * n_profile) as usize)
+    };
+
+    /*  Step sizes for the angle, and for the position along the curve, in    *
+     *  units of segments of the curve.                                       */
+    let dtheta: f32 = 2.0 * PI / (n_theta as f32);
+    let ds: f32 = ((n_profile - 1) as f32) / ((n_s - 1) as f32);
+
+    /*  The last segment of the curve, used for clamping below.               */
+    let last_segment: usize = (n_profile - 2) as usize;
+
+    /*  Whether to convert the points for y up, see set_up_axis.              */
+    let y_up: bool = is_y_up();
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Row-major with theta as the horizontal axis, index = s * n_theta +    *
+     *  theta.                                                                */
+    for s_index in 0..n_s {
+
+        /*  The position along the curve. The integer part is the segment and *
+         *  the fractional part is how far along it we are. Rounding can put  *
+         *  the last sample just past the end, so clamp the segment.          */
+        let s: f32 = (s_index as f32) * ds;
+        let segment: usize = (s.floor() as usize).min(last_segment);
+        let t: f32 = s - (segment as f32);
+
+        /*  Linear interpolation between the ends of the segment.             */
+        let r: f32 = profile[2 * segment]
+            + t * (profile[2 * segment + 2] - profile[2 * segment]);
+        let z: f32 = profile[2 * segment + 1]
+            + t * (profile[2 * segment + 3] - profile[2 * segment + 1]);
+
+        for theta_index in 0..n_theta {
+
+            /*  Sweep the point of the curve around the z axis.               */
+            let theta: f32 = (theta_index as f32) * dtheta;
+            let (sin_theta, cos_theta): (f32, f32) = theta.sin_cos();
+
+            arr[index] = r * cos_theta;
+            arr[index + 1] = r * sin_theta;
+            arr[index + 2] = z;
+            convert_up_axis(arr, index, y_up);
+
+            /*  Move on to the next point in the mesh. A point needs 3 f32's. */
+            index += 3;
+        }
+        /*  End of theta for-loop.                                            */
+    }
+    /*  End of s for-loop.                                                    */
+}
+/*  End of generate_revolution.                                               */
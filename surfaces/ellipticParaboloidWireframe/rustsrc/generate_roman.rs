@@ -0,0 +1,120 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the locations of the points in the mesh for Steiner's Roman  *
+ *      surface.                                                              *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  The angle u runs over [0, 2 pi) and v over [0, pi).                       */
+use std::f32::consts::PI;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Functions for converting the points for y up, see set_up_axis.            */
+use crate::up_axis::{is_y_up, convert_up_axis};
+
+/*  Function for generating the mesh for the Roman surface, an immersion of   *
+ *  the projective plane with self-intersections. It is the image of the      *
+ *  sphere of the given radius under (x, y, z) -> (xz, yz, xy). In terms of   *
+ *  the angles u and v:                                                       *
+ *                                                                            *
+ *          x = r^2 cos(u) cos(v) sin(v)                                      *
+ *          y = r^2 sin(u) cos(v) sin(v)                                      *
+ *          z = r^2 cos(u) sin(u) cos(v)^2                                    *
+ *                                                                            *
+ *  The surface crosses itself along three segments of the coordinate axes,   *
+ *  and each segment ends in a pinch point, where the surface comes to a      *
+ *  cusp. There are six of them, at distance r^2 / 2 from the origin, and the *
+ *  wireframe bunches up there. The row v = pi / 2 is the pole of the         *
+ *  sphere, a single point, so all of its vertices land on the origin and     *
+ *  the lines crowd together there too. Neither of these is an error.         *
+ *                                                                            *
+ *  Both angles are periodic, so neither interval includes its endpoint. The  *
+ *  wireframe is generate_indices_periodic(ptr, n_u, n_v, true, true, false). */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_roman(ptr: *mut f32, n_u: u32, n_v: u32, radius: f32) {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (n_u < 2) || (n_v < 2) {
+        return;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(n_u, n_v) != STATUS_OK {
+        return;
+    }
+
+    /*  The size of the array passed to us is given by the number of points   *
+     *  in the mesh, which is simply n_u times n_v. Each point is 3 f32's.    */
+    let len: usize = (3 * n_u * n_v) as usize;
+
+    /*  Get a slice for the data.                                             */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Step sizes for the two angles. Replacing v by v + pi gives the same   *
+     *  point, so v only needs to cover half a turn.                          */
+    let du: f32 = 2.0 * PI / (n_u as f32);
+    let dv: f32 = PI / (n_v as f32);
+
+    /*  Every coordinate is scaled by the square of the radius.               */
+    let rsq: f32 = radius * radius;
+
+    /*  Whether to convert the points for y up, see set_up_axis.              */
+    let y_up: bool = is_y_up();
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Row-major with u as the horizontal axis, index = v * n_u + u.         */
+    for v_index in 0..n_v {
+
+        /*  Convert the index to an angle.                                    */
+        let v: f32 = (v_index as f32) * dv;
+        let (sin_v, cos_v): (f32, f32) = v.sin_cos();
+        let cos_sin_v: f32 = rsq * cos_v * sin_v;
+        let cos_sq_v: f32 = rsq * cos_v * cos_v;
+
+        for u_index in 0..n_u {
+
+            /*  Convert the index to an angle.                                */
+            let u: f32 = (u_index as f32) * du;
+            let (sin_u, cos_u): (f32, f32) = u.sin_cos();
+
+            /*  Add this point to our vertex array.                           */
+            arr[index] = cos_u * cos_sin_v;
+            arr[index + 1] = sin_u * cos_sin_v;
+            arr[index + 2] = cos_u * sin_u * cos_sq_v;
+            convert_up_axis(arr, index, y_up);
+
+            /*  Move on to the next point in the mesh. A point needs 3 f32's. */
+            index += 3;
+        }
+        /*  End of u for-loop.                                                */
+    }
+    /*  End of v for-loop.                                                    */
+}
+/*  End of generate_roman.                                                    */
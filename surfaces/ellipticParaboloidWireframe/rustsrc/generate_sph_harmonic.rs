@@ -0,0 +1,153 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the locations of the points in the mesh for the radial plot  *
+ *      of a spherical harmonic.                                              *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  The polar angle runs over [0, pi] and the azimuthal angle over [0, 2 pi). */
+use std::f32::consts::PI;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Functions for converting the points for y up, see set_up_axis.            */
+use crate::up_axis::{is_y_up, convert_up_axis};
+
+/*  Evaluates the real spherical harmonic Y_l^m at the point (x, y, z) of the *
+ *  unit sphere. Only l <= 3 is supported, returning None otherwise. The      *
+ *  harmonics with m < 0 use sin(|m| phi), and those with m > 0 use cos(m     *
+ *  phi). Written in terms of x, y, and z they are polynomials, and the       *
+ *  constants are the usual normalizations sqrt((2l + 1) / (4 pi)) etc.       */
+#[allow(clippy::excessive_precision,
+        reason = "normalizations are written to the usual published digits")]
+fn real_sph_harmonic(l: u32, m: i32, x: f32, y: f32, z: f32) -> Option<f32> {
+    let zsq: f32 = z * z;
+
+    let value: f32 = match (l, m) {
+        (0, 0) => 0.282094792,
+        (1, -1) => 0.488602512 * y,
+        (1, 0) => 0.488602512 * z,
+        (1, 1) => 0.488602512 * x,
+        (2, -2) => 1.092548431 * x * y,
+        (2, -1) => 1.092548431 * y * z,
+        (2, 0) => 0.315391565 * (3.0 * zsq - 1.0),
+        (2, 1) => 1.092548431 * x * z,
+        (2, 2) => 0.546274215 * (x * x - y * y),
+        (3, -3) => 0.590043589 * y * (3.0 * x * x - y * y),
+        (3, -2) => 2.890611442 * x * y * z,
+        (3, -1) => 0.457045799 * y * (5.0 * zsq - 1.0),
+        (3, 0) => 0.373176333 * z * (5.0 * zsq - 3.0),
+        (3, 1) => 0.457045799 * x * (5.0 * zsq - 1.0),
+        (3, 2) => 1.445305721 * z * (x * x - y * y),
+        (3, 3) => 0.590043589 * x * (x * x - 3.0 * y * y),
+        _ => return None,
+    };
+
+    return Some(value);
+}
+
+/*  Function for generating the mesh for the surface r = |Y_l^m(theta, phi)|, *
+ *  the usual picture of an atomic orbital. Each direction on the sphere is   *
+ *  pushed out to the size of the harmonic in that direction. l must be at    *
+ *  most 3 and |m| at most l, nothing is written otherwise. The polar angle   *
+ *  theta is the vertical axis and the azimuthal angle phi is the horizontal  *
+ *  one, so the wireframe is                                                  *
+ *  generate_indices_periodic(ptr, n_phi, n_theta, true, false, false).       */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_sph_harmonic(ptr: *mut f32, n_theta: u32, n_phi: u32,
+                             l: u32, m: i32) {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (n_theta < 2) || (n_phi < 2) {
+        return;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  The azimuthal angle plays the role of the horizontal axis.            */
+    if capacity_status(n_phi, n_theta) != STATUS_OK {
+        return;
+    }
+
+    /*  Check that the table has this harmonic before writing anything.       */
+    if real_sph_harmonic(l, m, 0.0, 0.0, 1.0).is_none() {
+        return;
+    }
+
+    /*  The size of the array passed to us is given by the number of points   *
+     *  in the mesh. Each point needs three f32's.                            */
+    let len: usize = (3 * n_theta * n_phi) as usize;
+
+    /*  Get a slice for the data.                                             */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Step sizes for the two angles. The azimuthal angle omits 2 pi since   *
+     *  the seam is closed by the indices. The polar angle includes both      *
+     *  poles.                                                                */
+    let dtheta: f32 = PI / ((n_theta - 1) as f32);
+    let dphi: f32 = 2.0 * PI / (n_phi as f32);
+
+    /*  Whether to convert the points for y up, see set_up_axis.              */
+    let y_up: bool = is_y_up();
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Row-major with phi as the horizontal axis, index = theta * n_phi +    *
+     *  phi.                                                                  */
+    for theta_index in 0..n_theta {
+
+        /*  Convert the index to the polar angle.                             */
+        let theta: f32 = (theta_index as f32) * dtheta;
+        let (sin_theta, cos_theta): (f32, f32) = theta.sin_cos();
+
+        for phi_index in 0..n_phi {
+
+            /*  Convert the index to the azimuthal angle, giving the          *
+             *  direction.                                                    */
+            let phi: f32 = (phi_index as f32) * dphi;
+            let (sin_phi, cos_phi): (f32, f32) = phi.sin_cos();
+
+            let x: f32 = sin_theta * cos_phi;
+            let y: f32 = sin_theta * sin_phi;
+            let z: f32 = cos_theta;
+
+            /*  The harmonic is in the table, this was checked above.         */
+            let r: f32 = real_sph_harmonic(l, m, x, y, z).unwrap_or(0.0).abs();
+
+            /*  Add this point to our vertex array.                           */
+            arr[index] = r * x;
+            arr[index + 1] = r * y;
+            arr[index + 2] = r * z;
+            convert_up_axis(arr, index, y_up);
+
+            /*  Move on to the next point in the mesh. A point needs 3 f32's. */
+            index += 3;
+        }
+        /*  End of phi for-loop.                                              */
+    }
+    /*  End of theta for-loop.                                                */
+}
+/*  End of generate_sph_harmonic.                                             */
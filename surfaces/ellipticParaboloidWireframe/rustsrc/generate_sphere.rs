@@ -0,0 +1,121 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the locations of the points in the mesh for a sphere using   *
+ *      spherical coordinates.                                                *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  The polar angle runs over [0, pi] and the azimuthal angle over [0, 2 pi]. */
+use std::f32::consts::PI;
+
+/*  Computes the length of the buffer, checking for overflow.                 */
+use crate::checked_len::checked_len;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Functions for converting the points for y up, see set_up_axis.            */
+use crate::up_axis::{is_y_up, convert_up_axis};
+
+/*  Function for generating the mesh for a sphere by calculating vertices.    */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_sphere(ptr: *mut f32, n_theta: u32, n_phi: u32, radius: f32) {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (n_theta < 2) || (n_phi < 2) {
+        return;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  The azimuthal angle plays the role of the horizontal axis, and the    *
+     *  polar angle plays the role of the vertical one. Check the sizes.      */
+    if capacity_status(n_phi, n_theta) != STATUS_OK {
+        return;
+    }
+
+    /*  The size of the array passed to us is given by the number of points   *
+     *  in the mesh, which is the number of polar angles times the number of  *
+     *  azimuthal angles. Each point needs three f32's.                       *
+     *  The product is computed with overflow checking.                       */
+    let len: usize = match checked_len(n_theta, n_phi, 3) {
+        Some(len) => len,
+        None => return,
+    };
+
+    /*  Get a slice for the data.                                             */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Step sizes for the polar and azimuthal angles.                        */
+    let dtheta: f32 = PI / ((n_theta - 1) as f32);
+    let dphi: f32 = 2.0 * PI / ((n_phi - 1) as f32);
+
+    /*  Whether to convert the points for y up, see set_up_axis.              */
+    let y_up: bool = is_y_up();
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Loop through the polar angle. Since this is the outer for-loop, the   *
+     *  array is indexed in row-major fashion with the azimuthal angle as the *
+     *  horizontal axis. That is, index = theta * n_phi + phi. The wireframe  *
+     *  is obtained from generate_indices(ptr, n_phi, n_theta).               *
+     *                                                                        *
+     *  Note, the rows theta = 0 and theta = pi each collapse to a single     *
+     *  point, the north and south poles, respectively. We still write all    *
+     *  n_phi copies of these points so that the vertex array is completely   *
+     *  filled and the parameter grid remains rectangular. The wireframe will *
+     *  then contain a few zero-length edges at the poles, which is harmless. *
+     *  Similarly, the columns phi = 0 and phi = 2 pi coincide.               */
+    for theta_index in 0..n_theta {
+
+        /*  Convert the index to a polar angle, and compute the height.       */
+        let theta: f32 = (theta_index as f32) * dtheta;
+        let rho: f32 = radius * theta.sin();
+        let z_pt: f32 = radius * theta.cos();
+
+        /*  Loop through the azimuthal angle.                                 */
+        for phi_index in 0..n_phi {
+
+            /*  Convert the index to an azimuthal angle.                      */
+            let phi: f32 = (phi_index as f32) * dphi;
+
+            /*  Add this point to our vertex array. The sphere is given by:   *
+             *                                                                *
+             *      x = r sin(theta) cos(phi)                                 *
+             *      y = r sin(theta) sin(phi)                                 *
+             *      z = r cos(theta)                                          */
+            arr[index] = rho * phi.cos();
+            arr[index + 1] = rho * phi.sin();
+            arr[index + 2] = z_pt;
+            convert_up_axis(arr, index, y_up);
+
+            /*  Move on to the next point in the mesh. A point needs 3 f32's. */
+            index += 3;
+        }
+        /*  End of azimuthal for-loop.                                        */
+    }
+    /*  End of polar for-loop.                                                */
+}
+/*  End of generate_sphere.                                                   */
@@ -0,0 +1,120 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the locations of the points in the mesh for a superellipsoid.*
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  The latitude runs over [-pi / 2, pi / 2] and the longitude over [0, 2 pi).*/
+use std::f32::consts::{FRAC_PI_2, PI};
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Functions for converting the points for y up, see set_up_axis.            */
+use crate::up_axis::{is_y_up, convert_up_axis};
+
+/*  Computes sign(t) |t|^e, keeping the sign of t for fractional powers.      */
+fn signed_pow(t: f32, e: f32) -> f32 {
+    return t.signum() * t.abs().powf(e);
+}
+/*  End of signed_pow.                                                        */
+
+/*  Function for generating the mesh for a superellipsoid. The exponent e1    *
+ *  controls the shape along the latitude and e2 along the longitude. With    *
+ *  both equal to 1 this is the unit sphere, as they go to 0 the surface      *
+ *  approaches a cube, and at 2 it is an octahedron.                          */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_superellipsoid(ptr: *mut f32, n_u: u32, n_v: u32,
+                               e1: f32, e2: f32) {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (n_u < 2) || (n_v < 2) {
+        return;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(n_u, n_v) != STATUS_OK {
+        return;
+    }
+
+    /*  The size of the array passed to us is given by the number of points   *
+     *  in the mesh, which is simply n_u times n_v. Each point is 3 f32's.    */
+    let len: usize = (3 * n_u * n_v) as usize;
+
+    /*  Get a slice for the data.                                             */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Step sizes for the two angles. The longitude u runs over [0, 2 pi)    *
+     *  and omits the right endpoint, which is the same as u = 0. The         *
+     *  latitude v runs over the closed interval [-pi / 2, pi / 2], from the  *
+     *  south pole to the north pole.                                         */
+    let du: f32 = 2.0 * PI / (n_u as f32);
+    let dv: f32 = PI / ((n_v - 1) as f32);
+
+    /*  Whether to convert the points for y up, see set_up_axis.              */
+    let y_up: bool = is_y_up();
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Loop through the latitude. Since this is the outer for-loop, the      *
+     *  array is indexed in row-major fashion with u as the horizontal axis.  *
+     *  That is, index = v * n_u + u. The seam in u is closed by the          *
+     *  wireframe, use                                                        *
+     *  generate_indices_periodic(ptr, n_u, n_v, true, false, false).         */
+    for v_index in 0..n_v {
+
+        /*  Convert the index to an angle. These factors only depend on v.    */
+        let v: f32 = -FRAC_PI_2 + (v_index as f32) * dv;
+        let rho: f32 = signed_pow(v.cos(), e1);
+        let z_pt: f32 = signed_pow(v.sin(), e1);
+
+        /*  Loop through the longitude.                                       */
+        for u_index in 0..n_u {
+
+            /*  Convert the index to an angle.                                */
+            let u: f32 = (u_index as f32) * du;
+
+            /*  Add this point to our vertex array. The superellipsoid is:    *
+             *                                                                *
+             *        x = c(v, e1) c(u, e2)                                   *
+             *        y = c(v, e1) s(u, e2)                                   *
+             *        z = s(v, e1)                                            *
+             *                                                                *
+             *  where c(t, e) = sign(cos t) |cos t|^e and similarly for s.    */
+            arr[index] = rho * signed_pow(u.cos(), e2);
+            arr[index + 1] = rho * signed_pow(u.sin(), e2);
+            arr[index + 2] = z_pt;
+            convert_up_axis(arr, index, y_up);
+
+            /*  Move on to the next point in the mesh. A point needs 3 f32's. */
+            index += 3;
+        }
+        /*  End of u for-loop.                                                */
+    }
+    /*  End of v for-loop.                                                    */
+}
+/*  End of generate_superellipsoid.                                           */
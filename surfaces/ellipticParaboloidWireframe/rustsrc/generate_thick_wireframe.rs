@@ -0,0 +1,238 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes a wireframe made of thin quads, giving lines of a fixed      *
+ *      thickness.                                                            *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Physical width and height of the surface (projection onto the xy plane).  */
+pub use crate::{PARABOLOID_WIDTH, PARABOLOID_HEIGHT};
+
+/*  Left-most and bottom-most extremes of the surface (projected to xy plane).*/
+pub use crate::{PARABOLOID_X_START, PARABOLOID_Y_START};
+
+/*  Number of elements in the index array for the given dimensions.           */
+use crate::index_count::index_count;
+
+/*  Computes the length of a slice, returning None if it does not fit.        */
+use crate::checked_len::checked_len;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Global for the shift in the z axis, see set_height_shift.                 */
+use crate::HEIGHT_SHIFT;
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  Functions for converting the points for y up, see set_up_axis.            */
+use crate::up_axis::{is_y_up, convert_up_axis};
+
+/*  Function for forgetting the wireframe cached by generateIndicesCached.    */
+use crate::invalidate_index_cache::invalidate_index_cache;
+
+/*  Function for appending the quad for the edge from a to b. The quad is the *
+ *  edge pushed out by offset on either side, perpendicular to the edge in    *
+ *  the xy plane, split into two triangles. The points are computed with z    *
+ *  up, and the corners are converted for y up when it is set.                */
+fn add_quad(verts: &mut [f32], indices: &mut [u32], edge: usize,
+            a: [f32; 3], b: [f32; 3], offset: f32, y_up: bool) {
+
+    /*  The direction of the edge projected onto the xy plane. Rotating it by *
+     *  90 degrees gives the direction to push the corners out in.            */
+    let dx: f32 = b[0] - a[0];
+    let dy: f32 = b[1] - a[1];
+    let norm: f32 = (dx * dx + dy * dy).sqrt();
+
+    /*  Edges of the grid always have a non-zero length in the plane, but     *
+     *  avoid dividing by zero regardless. This gives a flat, invisible quad. */
+    let (px, py): (f32, f32) = if norm > 0.0 {
+        (-dy * offset / norm, dx * offset / norm)
+    } else {
+        (0.0, 0.0)
+    };
+
+    /*  Each edge has four corners, and each corner needs 3 f32's.            */
+    let v: usize = 12 * edge;
+    let corners: [[f32; 3]; 4] = [
+        [a[0] - px, a[1] - py, a[2]],
+        [a[0] + px, a[1] + py, a[2]],
+        [b[0] + px, b[1] + py, b[2]],
+        [b[0] - px, b[1] - py, b[2]]
+    ];
+
+    for (n, corner) in corners.iter().enumerate() {
+        verts[v + 3 * n] = corner[0];
+        verts[v + 3 * n + 1] = corner[1];
+        verts[v + 3 * n + 2] = corner[2];
+        convert_up_axis(verts, v + 3 * n, y_up);
+    }
+
+    /*  The two triangles (0, 1, 2) and (0, 2, 3) make up the quad.           */
+    let base: u32 = (4 * edge) as u32;
+    let i: usize = 6 * edge;
+    indices[i] = base;
+    indices[i + 1] = base + 1;
+    indices[i + 2] = base + 2;
+    indices[i + 3] = base;
+    indices[i + 4] = base + 2;
+    indices[i + 5] = base + 3;
+}
+
+/*  Function for generating the wireframe as triangles. GL lines are always   *
+ *  one pixel wide, which is hard to see on high resolution screens. The same *
+ *  edges as generate_indices are written as quads of width 2 * offset. The   *
+ *  vertices go in vert_ptr, four per edge, and the triangle indices go in    *
+ *  index_ptr, six per edge. The arrays are allocated by the caller, and      *
+ *  vert_len and index_len are their lengths in elements. Returns the number  *
+ *  of indices written, or zero if the dimensions are invalid or the quads do *
+ *  not fit in the arrays.                                                    */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_thick_wireframe(vert_ptr: *mut f32, vert_len: u32,
+                                index_ptr: *mut u32, index_len: u32,
+                                nx_pts: u32, ny_pts: u32,
+                                offset: f32) -> u32 {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return 0;
+    }
+
+    /*  Avoiding writing beyond the bounds of the arrays that were allocated. *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(nx_pts, ny_pts) != STATUS_OK {
+        return 0;
+    }
+
+    /*  generate_indices uses two indices per edge, giving the edge count.    */
+    let n_edges: u32 = index_count(nx_pts, ny_pts) / 2;
+
+    /*  Each edge has four corners of three f32's, and six indices.           */
+    let n_floats: usize = match checked_len(n_edges, 4, 3) {
+        Some(len) => len,
+        None => return 0,
+    };
+
+    let n_indices: usize = match checked_len(n_edges, 6, 1) {
+        Some(len) => len,
+        None => return 0,
+    };
+
+    /*  Avoiding writing beyond the bounds of the arrays from the caller.     */
+    if (n_floats > (vert_len as usize)) || (n_indices > (index_len as usize)) {
+        return 0;
+    }
+
+    /*  The array may hold the wireframe cached by generateIndicesCached.     */
+    invalidate_index_cache();
+
+    /*  Get slices for the data.                                              */
+    let verts = unsafe { std::slice::from_raw_parts_mut(vert_ptr, n_floats) };
+    let indices = unsafe {
+        std::slice::from_raw_parts_mut(index_ptr, n_indices)
+    };
+
+    /*  Step sizes in the horizontal and vertical axes.                       */
+    let dx: f32 = PARABOLOID_WIDTH / ((nx_pts - 1) as f32);
+    let dy: f32 = PARABOLOID_HEIGHT / ((ny_pts - 1) as f32);
+
+    /*  Shift factor in the z axis, read once rather than for every point.    */
+    let height_shift: f32 = *lock(&HEIGHT_SHIFT);
+
+    /*  Whether to convert the corners for y up, see set_up_axis.             */
+    let y_up: bool = is_y_up();
+
+    /*  The point on the surface for the given grid indices.                  */
+    let point = |x_index: u32, y_index: u32| -> [f32; 3] {
+        let x_pt: f32 = PARABOLOID_X_START + (x_index as f32) * dx;
+        let y_pt: f32 = PARABOLOID_Y_START + (y_index as f32) * dy;
+        return [x_pt, y_pt, x_pt * x_pt + 2.0 * y_pt * y_pt + height_shift];
+    };
+
+    /*  Variable for counting the edges that have been written.               */
+    let mut edge: usize = 0;
+
+    /*  Same "L" shapes as generate_indices, in the same order.               */
+    for y_index in 0..ny_pts {
+        for x_index in 0..nx_pts {
+
+            let p00: [f32; 3] = point(x_index, y_index);
+
+            /*  The vertical edge, skipped at the top of the rectangle.       */
+            if y_index != ny_pts - 1 {
+                add_quad(verts, indices, edge, p00,
+                         point(x_index, y_index + 1), offset, y_up);
+                edge += 1;
+            }
+
+            /*  The horizontal edge, skipped at the right of the rectangle.   */
+            if x_index != nx_pts - 1 {
+                add_quad(verts, indices, edge, p00,
+                         point(x_index + 1, y_index), offset, y_up);
+                edge += 1;
+            }
+        }
+        /*  End of horizontal for-loop.                                       */
+    }
+    /*  End of vertical for-loop.                                             */
+
+    return (6 * edge) as u32;
+}
+/*  End of generate_thick_wireframe.                                          */
+
+#[cfg(test)]
+mod tests {
+    use super::generate_thick_wireframe;
+    use crate::TEST_LOCK;
+
+    /*  A 3x2 grid has 7 edges, needing 84 floats and 42 indices. Arrays one  *
+     *  element short are rejected without being written to.                  */
+    #[test]
+    fn rejects_arrays_that_are_too_short() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let mut verts: [f32; 84] = [0.0; 84];
+        let mut indices: [u32; 42] = [0; 42];
+
+        let short_verts: u32 = generate_thick_wireframe(
+            verts.as_mut_ptr(), 83, indices.as_mut_ptr(), 42, 3, 2, 0.1
+        );
+
+        let short_indices: u32 = generate_thick_wireframe(
+            verts.as_mut_ptr(), 84, indices.as_mut_ptr(), 41, 3, 2, 0.1
+        );
+
+        assert_eq!(short_verts, 0);
+        assert_eq!(short_indices, 0);
+        assert!(indices.iter().all(|&index| index == 0));
+
+        let written: u32 = generate_thick_wireframe(
+            verts.as_mut_ptr(), 84, indices.as_mut_ptr(), 42, 3, 2, 0.1
+        );
+
+        assert_eq!(written, 42);
+        assert_eq!(indices[41], 27);
+    }
+}
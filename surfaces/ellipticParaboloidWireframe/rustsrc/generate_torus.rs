@@ -0,0 +1,118 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the locations of the points in the mesh for a torus.         *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Both angles of the torus run over the full circle.                        */
+use std::f32::consts::PI;
+
+/*  Computes the length of the buffer, checking for overflow.                 */
+use crate::checked_len::checked_len;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Functions for converting the points for y up, see set_up_axis.            */
+use crate::up_axis::{is_y_up, convert_up_axis};
+
+/*  Function for generating the mesh for a torus by calculating vertices.     */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_torus(ptr: *mut f32, n_u: u32, n_v: u32,
+                      major_r: f32, minor_r: f32) {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (n_u < 2) || (n_v < 2) {
+        return;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(n_u, n_v) != STATUS_OK {
+        return;
+    }
+
+    /*  The size of the array passed to us is given by the number of points   *
+     *  in the mesh, which is simply n_u times n_v. Each point is 3 f32's.    *
+     *  The product is computed with overflow checking.                       */
+    let len: usize = match checked_len(n_u, n_v, 3) {
+        Some(len) => len,
+        None => return,
+    };
+
+    /*  Get a slice for the data.                                             */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Step sizes for the two angles. The angle u, which goes around the     *
+     *  central axis, runs over [0, 2 pi) and omits the right endpoint since  *
+     *  this is the same as u = 0. The seam is closed by the wireframe, see   *
+     *  generate_indices_wrapped. The angle v, which goes around the tube,    *
+     *  runs over the closed interval [0, 2 pi], so the top row of the mesh   *
+     *  duplicates the bottom row and the plain lattice closes this seam.     */
+    let du: f32 = 2.0 * PI / (n_u as f32);
+    let dv: f32 = 2.0 * PI / ((n_v - 1) as f32);
+
+    /*  Whether to convert the points for y up, see set_up_axis.              */
+    let y_up: bool = is_y_up();
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Loop through the angle around the tube. Since this is the outer       *
+     *  for-loop, the array is indexed in row-major fashion with u as the     *
+     *  horizontal axis. That is, index = v * n_u + u. The wireframe is given *
+     *  by generate_indices_wrapped(ptr, n_u, n_v, true).                     */
+    for v_index in 0..n_v {
+
+        /*  Convert the index to an angle. The distance from the central axis *
+         *  and the height only depend on v, compute these.                   */
+        let v: f32 = (v_index as f32) * dv;
+        let rho: f32 = major_r + minor_r * v.cos();
+        let z_pt: f32 = minor_r * v.sin();
+
+        /*  Loop through the angle around the central axis.                   */
+        for u_index in 0..n_u {
+
+            /*  Convert the index to an angle.                                */
+            let u: f32 = (u_index as f32) * du;
+
+            /*  Add this point to our vertex array. The torus is given by:    *
+             *                                                                *
+             *      x = (R + r cos(v)) cos(u)                                 *
+             *      y = (R + r cos(v)) sin(u)                                 *
+             *      z = r sin(v)                                              */
+            arr[index] = rho * u.cos();
+            arr[index + 1] = rho * u.sin();
+            arr[index + 2] = z_pt;
+            convert_up_axis(arr, index, y_up);
+
+            /*  Move on to the next point in the mesh. A point needs 3 f32's. */
+            index += 3;
+        }
+        /*  End of u for-loop.                                                */
+    }
+    /*  End of v for-loop.                                                    */
+}
+/*  End of generate_torus.                                                    */
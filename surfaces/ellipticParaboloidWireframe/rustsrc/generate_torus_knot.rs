@@ -0,0 +1,96 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Generates the points of a (p, q) torus knot as a closed polyline.     *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Number of points the mesh buffer currently has room for.                  */
+use crate::buffer_capacity::point_capacity;
+
+/*  The parameter runs over [0, 2 pi).                                        */
+use std::f32::consts::PI;
+
+/*  Functions for converting the points for y up, see set_up_axis.            */
+use crate::up_axis::{is_y_up, convert_up_axis};
+
+/*  Function for generating n points along the (p, q) torus knot, which winds *
+ *  p times around the axis of the torus and q times around its tube. The     *
+ *  point for t is ((R + r cos(q t)) cos(p t), (R + r cos(q t)) sin(p t), r   *
+ *  sin(q t)), with R the major radius and r the minor one. This is a curve   *
+ *  rather than a surface, so the points are a single row and there is no     *
+ *  grid. Use generate_indices_loop for the line segments joining them.       *
+ *  Returns the number of points written, or zero if the input is invalid.    */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_torus_knot(ptr: *mut f32, n_pts: u32, p: u32, q: u32,
+                           major_r: f32, minor_r: f32) -> u32 {
+
+    /*  A closed loop needs at least three points to enclose anything.        */
+    if n_pts < 3 {
+        return 0;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  The curve may use the whole mesh buffer, so check the total size.     */
+    if (n_pts as usize) > point_capacity() {
+        return 0;
+    }
+
+    /*  Get a slice for the data. Each point needs three f32's.               */
+    let len: usize = (3 * n_pts) as usize;
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  The loop is closed by the indices, so the parameter stops one step    *
+     *  short of 2 pi. Otherwise the first and last points would coincide.    */
+    let dt: f32 = 2.0 * PI / (n_pts as f32);
+
+    /*  The winding numbers as floats, used for the two angles.               */
+    let p_val: f32 = p as f32;
+    let q_val: f32 = q as f32;
+
+    /*  Whether to convert the points for y up, see set_up_axis.              */
+    let y_up: bool = is_y_up();
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    for t_index in 0..n_pts {
+
+        /*  The angles around the axis and around the tube.                   */
+        let t_val: f32 = (t_index as f32) * dt;
+        let phi: f32 = p_val * t_val;
+        let theta: f32 = q_val * t_val;
+
+        /*  Distance from the axis of the torus.                              */
+        let rho: f32 = major_r + minor_r * theta.cos();
+
+        arr[index] = rho * phi.cos();
+        arr[index + 1] = rho * phi.sin();
+        arr[index + 2] = minor_r * theta.sin();
+        convert_up_axis(arr, index, y_up);
+
+        /*  Move on to the next point on the curve. A point needs 3 f32's.    */
+        index += 3;
+    }
+    /*  End of t for-loop.                                                    */
+
+    return n_pts;
+}
+/*  End of generate_torus_knot.                                               */
@@ -0,0 +1,114 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the indices for a triangulation of the mesh, used for        *
+ *      rendering solid surfaces.                                             *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Computes the length of the buffer, checking for overflow.                 */
+use crate::checked_len::checked_len;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Function for forgetting the wireframe cached by generateIndicesCached.    */
+use crate::invalidate_index_cache::invalidate_index_cache;
+
+/*  Function for generating the triangles for a solid surface.                */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn generate_triangle_indices(ptr: *mut u32, nx_pts: u32, ny_pts: u32) {
+
+    /*  A grid needs at least two points along each axis. Otherwise the       *
+     *  length of the array underflows, so return early.                      */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(nx_pts, ny_pts) != STATUS_OK {
+        return;
+    }
+
+    /*  Each square in the grid is split into two triangles, and each         *
+     *  triangle needs three indices. There are (w - 1) (h - 1) squares,      *
+     *  where w is the width and h is the height, so 6 (w - 1) (h - 1)        *
+     *  elements in total. The product is checked for overflow.               */
+    let len: usize = match checked_len(nx_pts - 1, ny_pts - 1, 6) {
+        Some(len) => len,
+        None => return,
+    };
+
+    /*  The array may hold the wireframe cached by generateIndicesCached.     */
+    invalidate_index_cache();
+
+    /*  Convert the pointer into a slice.                                     */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Loop over the bottom left corners of the squares in the grid. The top *
+     *  row and right-most column are not the bottom left corners of any      *
+     *  square, so we stop one short in both directions.                      */
+    for y_index in 0..(ny_pts - 1) {
+
+        /*  The indices are row-major, meaning index = y * width + x. The     *
+         *  shift factor only depends on the y-component, compute this.       */
+        let shift: u32 = y_index * nx_pts;
+
+        /*  The vertical component is now fixed, loop through the horizontal. */
+        for x_index in 0..(nx_pts - 1) {
+
+            /*  The bottom left corner of the current square.                 */
+            let index00: u32 = shift + x_index;
+
+            /*  The bottom right corner, directly after the current point.    */
+            let index01: u32 = index00 + 1;
+
+            /*  The top left corner, directly above the current point.        */
+            let index10: u32 = index00 + nx_pts;
+
+            /*  The top right corner, diagonal to the current point.          */
+            let index11: u32 = index10 + 1;
+
+            /*  Split the square along the diagonal from the bottom left to   *
+             *  the top right. Both triangles are listed counter-clockwise    *
+             *  when viewed from above, so their normals agree.               */
+            arr[index] = index00;
+            arr[index + 1] = index01;
+            arr[index + 2] = index11;
+
+            arr[index + 3] = index00;
+            arr[index + 4] = index11;
+            arr[index + 5] = index10;
+
+            /*  Move on to the next square. Two triangles need 6 indices.     */
+            index += 6;
+        }
+        /*  End of horizontal for-loop.                                       */
+    }
+    /*  End of vertical for-loop.                                             */
+}
+/*  End of generate_triangle_indices.                                         */
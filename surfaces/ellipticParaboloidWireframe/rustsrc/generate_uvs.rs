@@ -0,0 +1,85 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the texture coordinates for the mesh in the static UV buffer.*
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Buffer for the texture coordinates, shared with JavaScript.               */
+use crate::UV_BUFFER;
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  The current capacity of the buffers, and allocation on first use.         */
+use crate::buffer_capacity::{buffer_capacity, reserve_buffer};
+
+/*  Number of elements in the texture coordinate array for given dimensions.  */
+use crate::uv_float_count::uv_float_count;
+
+/*  Function for generating the texture coordinates for the surface.          */
+pub fn generate_uvs(nx_pts: u32, ny_pts: u32) {
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return;
+    }
+
+    /*  The static buffer has room for the dimensions given to init_buffers,  *
+     *  MAX_WIDTH times MAX_HEIGHT by default. Check if the input is too big. */
+    let (width, height): (u32, u32) = buffer_capacity();
+
+    if (nx_pts > width) || (ny_pts > height) {
+        return;
+    }
+
+    /*  The texture coordinates run from 0 to 1 across the grid.              */
+    let du: f32 = 1.0 / ((nx_pts - 1) as f32);
+    let dv: f32 = 1.0 / ((ny_pts - 1) as f32);
+
+    /*  Get the buffer, and a variable for indexing over it.                  */
+    let mut buffer = lock(&UV_BUFFER);
+    let mut index: usize = 0;
+
+    /*  The buffer starts out empty, allocate it if this is the first use.    */
+    reserve_buffer(&mut buffer, uv_float_count(width, height) as usize);
+
+    /*  Loop through the points in the same row-major order as generate_mesh  *
+     *  so that the i^th pair of coordinates corresponds to the i^th vertex.  */
+    for y_index in 0..ny_pts {
+
+        /*  The v coordinate only depends on the row.                         */
+        let v: f32 = (y_index as f32) * dv;
+
+        for x_index in 0..nx_pts {
+
+            /*  The u coordinate only depends on the column.                  */
+            buffer[index] = (x_index as f32) * du;
+            buffer[index + 1] = v;
+
+            /*  Move on to the next point in the mesh. A UV pair needs 2      *
+             *  f32's.                                                        */
+            index += 2;
+        }
+        /*  End of horizontal for-loop.                                       */
+    }
+    /*  End of vertical for-loop.                                             */
+}
+/*  End of generate_uvs.                                                      */
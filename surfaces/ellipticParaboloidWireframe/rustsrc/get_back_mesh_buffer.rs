@@ -0,0 +1,63 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Returns the address of the back mesh buffer, the one not being        *
+ *      rendered.                                                             *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  The two buffers for the mesh, and which of the two is in front.           */
+use crate::{MESH_BUFFER, MESH_BUFFER_BACK, MESH_BUFFERS_SWAPPED};
+
+/*  Used for reading the flag set by swap_mesh_buffers.                       */
+use std::sync::atomic::Ordering;
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  The current capacity of the buffers, and allocation on first use.         */
+use crate::buffer_capacity::{buffer_capacity, reserve_buffer};
+
+/*  Number of elements in the vertex array for the given dimensions.          */
+use crate::vertex_float_count::vertex_float_count;
+
+/*  Function for getting the address of the back mesh array. This is the      *
+ *  buffer that new frames should be written to.                              */
+pub fn get_back_mesh_buffer() -> usize {
+
+    /*  The Mutex stores its state alongside the data, so the address of the  *
+     *  static itself is not the address of the array. Lock the buffer and    *
+     *  get a raw pointer to the first element of the data instead. The back  *
+     *  buffer is whichever one get_mesh_buffer does not return.              */
+    let mut buffer = if MESH_BUFFERS_SWAPPED.load(Ordering::Acquire) {
+        lock(&MESH_BUFFER)
+    } else {
+        lock(&MESH_BUFFER_BACK)
+    };
+
+    /*  The buffers start out empty. Make sure this one has room for the      *
+     *  current capacity before handing out its address.                      */
+    let (width, height): (u32, u32) = buffer_capacity();
+    reserve_buffer(&mut buffer, vertex_float_count(width, height) as usize);
+    let ptr: *const f32 = buffer.as_ptr();
+
+    /*  Treating the pointer as an integer gives us the address.              */
+    return ptr as usize;
+}
+/*  End of get_back_mesh_buffer.                                              */
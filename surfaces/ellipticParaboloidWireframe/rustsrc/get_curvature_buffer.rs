@@ -0,0 +1,49 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Returns the address of the curvature buffer.                          *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Buffer for the curvature, shared with JavaScript.                         */
+use crate::CURVATURE_BUFFER;
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  The current capacity of the buffers, and allocation on first use.         */
+use crate::buffer_capacity::{buffer_capacity, reserve_buffer};
+
+/*  Function for getting the address of the curvature array.                  */
+pub fn get_curvature_buffer() -> usize {
+
+    /*  The buffers start out empty. Make sure this one has room for the      *
+     *  current capacity before handing out its address.                      */
+    let (width, height): (u32, u32) = buffer_capacity();
+    let mut buffer = lock(&CURVATURE_BUFFER);
+    reserve_buffer(&mut buffer, (width * height) as usize);
+
+    /*  As with get_mesh_buffer, the address of the static is not the address *
+     *  of the array. Get a pointer to the first element instead.             */
+    let ptr: *const f32 = buffer.as_ptr();
+
+    /*  Treating the pointer as an integer gives us the address.              */
+    return ptr as usize;
+}
+/*  End of get_curvature_buffer.                                              */
@@ -21,17 +21,30 @@
  *  Date:       November 3, 2025                                              *
  ******************************************************************************/
 
-/*  Synchonization primitive allowing safe access to shared data.             */
-use std::sync::Mutex;
+/*  Buffer for the indices.                                                   */
+use crate::INDEX_BUFFER;
 
-/*  Buffer for the indices, and the number of elements in the buffer.         */
-use crate::{INDEX_BUFFER, INDEX_SIZE};
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  The current capacity of the buffers, and allocation on first use.         */
+use crate::buffer_capacity::{buffer_capacity, reserve_buffer};
+
+/*  Number of elements in the index array for the given dimensions.           */
+use crate::index_count::index_count;
 
 /*  Function for getting the address of the index array.                      */
 pub fn get_index_buffer() -> usize {
 
-    /*  Get a raw pointer to the index buffer.                                */
-    let ptr: *const Mutex<[u32; INDEX_SIZE]> = &INDEX_BUFFER;
+    /*  The buffers start out empty. Make sure this one has room for the      *
+     *  current capacity before handing out its address.                      */
+    let (width, height): (u32, u32) = buffer_capacity();
+    let mut buffer = lock(&INDEX_BUFFER);
+    reserve_buffer(&mut buffer, index_count(width, height) as usize);
+
+    /*  As with get_mesh_buffer, the address of the Mutex is not the address  *
+     *  of the array. Get a pointer to the data instead.                      */
+    let ptr: *const u32 = buffer.as_ptr();
 
     /*  Treating the pointer as an integer gives us the address.              */
     return ptr as usize;
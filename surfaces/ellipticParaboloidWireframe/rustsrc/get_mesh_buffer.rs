@@ -21,17 +21,40 @@
  *  Date:       November 3, 2025                                              *
  ******************************************************************************/
 
-/*  Synchonization primitive allowing safe access to shared data.             */
-use std::sync::Mutex;
+/*  The two buffers for the mesh, and which of the two is in front.           */
+use crate::{MESH_BUFFER, MESH_BUFFER_BACK, MESH_BUFFERS_SWAPPED};
 
-/*  Buffer for the mesh, and the number of elements in the buffer.            */
-use crate::{MESH_BUFFER, MESH_SIZE};
+/*  Used for reading the flag set by swap_mesh_buffers.                       */
+use std::sync::atomic::Ordering;
 
-/*  Function for getting the address of the mesh array.                       */
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  The current capacity of the buffers, and allocation on first use.         */
+use crate::buffer_capacity::{buffer_capacity, reserve_buffer};
+
+/*  Number of elements in the vertex array for the given dimensions.          */
+use crate::vertex_float_count::vertex_float_count;
+
+/*  Function for getting the address of the mesh array. This is the front     *
+ *  buffer, the one that should be rendered.                                  */
 pub fn get_mesh_buffer() -> usize {
 
-    /*  Get a raw pointer to the mesh buffer.                                 */
-    let ptr: *const Mutex<[f32; MESH_SIZE]> = &MESH_BUFFER;
+    /*  The Mutex stores its state alongside the data, so the address of the  *
+     *  static itself is not the address of the array. Lock the buffer and    *
+     *  get a raw pointer to the first element of the data instead. The front *
+     *  buffer is MESH_BUFFER unless the buffers have been swapped.           */
+    let mut buffer = if MESH_BUFFERS_SWAPPED.load(Ordering::Acquire) {
+        lock(&MESH_BUFFER_BACK)
+    } else {
+        lock(&MESH_BUFFER)
+    };
+
+    /*  The buffers start out empty. Make sure this one has room for the      *
+     *  current capacity before handing out its address.                      */
+    let (width, height): (u32, u32) = buffer_capacity();
+    reserve_buffer(&mut buffer, vertex_float_count(width, height) as usize);
+    let ptr: *const f32 = buffer.as_ptr();
 
     /*  Treating the pointer as an integer gives us the address.              */
     return ptr as usize;
@@ -0,0 +1,84 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the number of elements in the index array for the wireframe. *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Function for computing the number of indices in the wireframe. This is    *
+ *  exported to JavaScript, so any input may be passed in. Zero is returned   *
+ *  for a grid with fewer than two points along an axis, and for dimensions   *
+ *  whose count does not fit in a u32.                                        */
+pub const fn index_count(nx_pts: u32, ny_pts: u32) -> u32 {
+
+    /*  A grid needs at least two points along each axis, otherwise there are *
+     *  no edges. This also keeps the subtraction below from underflowing.    */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return 0;
+    }
+
+    /*  The total number of points in the mesh.                               */
+    let number_of_points: u32 = match nx_pts.checked_mul(ny_pts) {
+        Some(n) => n,
+        None => return 0,
+    };
+
+    /*  For each vertex, there are two edges comming out of it forming an "L" *
+     *  shape. This is true for all points except those along the top edge    *
+     *  and the right-most edge, where there is only one edge. Lastly, for    *
+     *  each edge there are two vertices, the starting vertex and the         *
+     *  terminal one, meaning there are 2 (2wh - w - h) elements in the index *
+     *  array, where w is the width and h is the height.                      */
+    let two_wh: u32 = match number_of_points.checked_mul(2) {
+        Some(n) => n,
+        None => return 0,
+    };
+
+    /*  With w and h at least 2, 2wh - w - h is positive. The subtraction is  *
+     *  checked anyway, only the final doubling can still overflow.           */
+    let edges: u32 = match two_wh.checked_sub(nx_pts) {
+        Some(n) => match n.checked_sub(ny_pts) {
+            Some(n) => n,
+            None => return 0,
+        },
+        None => return 0,
+    };
+
+    return match edges.checked_mul(2) {
+        Some(n) => n,
+        None => 0,
+    };
+}
+/*  End of index_count.                                                       */
+
+#[cfg(test)]
+mod tests {
+    use super::index_count;
+
+    /*  Small grids have no edges, and huge ones give zero instead of a       *
+     *  wrapped count.                                                        */
+    #[test]
+    fn guards_degenerate_and_huge_input() {
+        assert_eq!(index_count(3, 2), 14);
+        assert_eq!(index_count(0, 5), 0);
+        assert_eq!(index_count(5, 1), 0);
+        assert_eq!(index_count(100000, 100000), 0);
+        assert_eq!(index_count(50000, 30000), 0);
+    }
+}
@@ -0,0 +1,98 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Allocates the static buffers for a given maximum mesh size.           *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Maximum number of pixels in the vertical and horizontal axes.             */
+pub use crate::{MAX_HEIGHT, MAX_WIDTH};
+
+/*  Status codes returned to JavaScript.                                      */
+use crate::{STATUS_DEGENERATE, STATUS_OK};
+
+/*  Status for a mesh that is too wide or too tall.                           */
+use crate::dimension_status::dimension_status;
+
+/*  The static buffers, and the dimensions they are allocated for.            */
+use crate::{BUFFER_DIMENSIONS, COLOR_BUFFER, CURVATURE_BUFFER, INDEX_BUFFER};
+use crate::{MESH_BUFFER, MESH_BUFFER_BACK, NORMAL_BUFFER, TRI_INDEX_BUFFER};
+use crate::{PERIODIC_INDEX_BUFFER, UV_BUFFER};
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  Number of elements in the vertex and index arrays for given dimensions.   */
+use crate::index_count::index_count;
+use crate::periodic_index_count::periodic_index_count;
+use crate::triangle_index_count::triangle_index_count;
+use crate::uv_float_count::uv_float_count;
+use crate::vertex_float_count::vertex_float_count;
+
+/*  The new index buffer does not hold the cached wireframe.                  */
+use crate::invalidate_index_cache::invalidate_index_cache;
+
+/*  Function for allocating the static buffers with room for max_w by max_h   *
+ *  points. Without this, the buffers are allocated with room for MAX_WIDTH   *
+ *  by MAX_HEIGHT points the first time they are used, about 3 MB each for    *
+ *  the vertex arrays, which is wasteful for a small mesh. Any address        *
+ *  returned by the get_*_buffer functions is invalid after this call,        *
+ *  JavaScript must fetch them again. Returns STATUS_OK on success.           */
+pub fn init_buffers(max_w: u32, max_h: u32) -> i32 {
+
+    /*  A grid needs at least two points along each axis.                     */
+    if (max_w < 2) || (max_h < 2) {
+        return STATUS_DEGENERATE;
+    }
+
+    /*  MAX_WIDTH and MAX_HEIGHT are still the upper limit. The index         *
+     *  arithmetic is done with u32's, and the const asserts in lib.rs only   *
+     *  guarantee it does not overflow for meshes no bigger than this.        */
+    let status: i32 = dimension_status(max_w, max_h, MAX_WIDTH, MAX_HEIGHT);
+
+    if status != STATUS_OK {
+        return status;
+    }
+
+    /*  The number of elements each buffer needs for these dimensions.        */
+    let mesh_len: usize = vertex_float_count(max_w, max_h) as usize;
+    let index_len: usize = index_count(max_w, max_h) as usize;
+    let tri_len: usize = triangle_index_count(max_w, max_h) as usize;
+    let periodic_len: usize = periodic_index_count(max_w, max_h) as usize;
+    let uv_len: usize = uv_float_count(max_w, max_h) as usize;
+    let curvature_len: usize = (max_w * max_h) as usize;
+
+    /*  Replace the buffers outright rather than resizing them, so that the   *
+     *  memory for a previous, larger size is actually given back.            */
+    *lock(&MESH_BUFFER) = vec![0.0; mesh_len];
+    *lock(&MESH_BUFFER_BACK) = vec![0.0; mesh_len];
+    *lock(&NORMAL_BUFFER) = vec![0.0; mesh_len];
+    *lock(&COLOR_BUFFER) = vec![0.0; mesh_len];
+    *lock(&INDEX_BUFFER) = vec![0; index_len];
+    *lock(&TRI_INDEX_BUFFER) = vec![0; tri_len];
+    *lock(&PERIODIC_INDEX_BUFFER) = vec![0; periodic_len];
+    *lock(&UV_BUFFER) = vec![0.0; uv_len];
+    *lock(&CURVATURE_BUFFER) = vec![0.0; curvature_len];
+
+    /*  The generators that write to the static buffers check against this.   */
+    *lock(&BUFFER_DIMENSIONS) = [max_w, max_h];
+    invalidate_index_cache();
+    return STATUS_OK;
+}
+/*  End of init_buffers.                                                      */
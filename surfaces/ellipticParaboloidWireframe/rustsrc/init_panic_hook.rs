@@ -0,0 +1,36 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Installs a panic hook that prints the Rust panic message to the       *
+ *      browser console.                                                      *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Function for installing the panic hook. By default a panic in wasm only   *
+ *  shows up as "unreachable executed" in the browser, with no hint of what   *
+ *  went wrong. With the panic-hook feature enabled the Rust message and a    *
+ *  stack trace are sent to console.error instead, using the                  *
+ *  console_error_panic_hook crate. Call this once when the module is loaded, *
+ *  calling it again is harmless. Without the feature this does nothing, so   *
+ *  it is safe to call from JavaScript in either build.                       */
+pub fn init_panic_hook() {
+    #[cfg(feature = "panic-hook")]
+    console_error_panic_hook::set_once();
+}
+/*  End of init_panic_hook.                                                   */
@@ -23,19 +23,69 @@
 #![crate_type = "lib"]
 #![crate_name = "paraboloid"]
 
+use coeff_easing::CoeffEasing;
 use std::sync::Mutex;
+use std::sync::atomic::AtomicBool;
 use wasm_bindgen::prelude::*;
 
 pub const MAX_WIDTH: u32 = 512;
 pub const MAX_HEIGHT: u32 = 512;
 
+pub const VERTEX_STRIDE: u32 = 3;
+pub const FLOAT_BYTES: u32 = std::mem::size_of::<f32>() as u32;
+
 pub const MAX_LENGTH: u32 = MAX_HEIGHT * MAX_WIDTH;
-pub const MESH_SIZE: usize = (3 * MAX_LENGTH) as usize;
+pub const MESH_SIZE: usize = (VERTEX_STRIDE * MAX_LENGTH) as usize;
+pub const UV_SIZE: usize = (2 * MAX_LENGTH) as usize;
 pub const INDEX_SIZE: usize = (2*(2*MAX_LENGTH-MAX_WIDTH-MAX_HEIGHT)) as usize;
+pub const TRI_INDEX_SIZE: usize = (6*(MAX_WIDTH-1)*(MAX_HEIGHT-1)) as usize;
+pub const PERIODIC_INDEX_SIZE: usize = (4 * MAX_LENGTH) as usize;
+
+/*  The buffer sizes above are written out by hand. Check at compile time     *
+ *  that they agree with what the generators write at the largest allowed     *
+ *  dimensions, so changing MAX_WIDTH or MAX_HEIGHT can not overrun them.     */
+const _: () = assert!(
+    MESH_SIZE == vertex_float_count::vertex_float_count(MAX_WIDTH, MAX_HEIGHT)
+        as usize
+);
+const _: () = assert!(
+    INDEX_SIZE == index_count::index_count(MAX_WIDTH, MAX_HEIGHT) as usize
+);
+const _: () = assert!(
+    TRI_INDEX_SIZE == triangle_index_count::triangle_index_count(
+        MAX_WIDTH, MAX_HEIGHT
+    ) as usize
+);
+const _: () = assert!(
+    PERIODIC_INDEX_SIZE == periodic_index_count::periodic_index_count(
+        MAX_WIDTH, MAX_HEIGHT
+    ) as usize
+);
+const _: () = assert!(
+    UV_SIZE == uv_float_count::uv_float_count(MAX_WIDTH, MAX_HEIGHT) as usize
+);
+
+pub const UP_AXIS_Y: u32 = 1;
+pub const UP_AXIS_Z: u32 = 2;
+
+/*  Status codes returned by the generators and by initBuffers. Input         *
+ *  larger than the buffers gives STATUS_TOO_WIDE or STATUS_TOO_TALL, naming  *
+ *  the dimension at fault. STATUS_TOO_LARGE is left for lengths that         *
+ *  overflow, and for the few functions without a single width and height.    */
+pub const STATUS_OK: i32 = 0;
+pub const STATUS_TOO_LARGE: i32 = -1;
+pub const STATUS_DEGENERATE: i32 = -2;
+pub const STATUS_INVALID: i32 = -3;
+pub const STATUS_TOO_WIDE: i32 = -4;
+pub const STATUS_TOO_TALL: i32 = -5;
 
 pub static ROTATION_ANGLE: Mutex<f32> = Mutex::new(0.0);
 pub static COS_ANGLE: Mutex<f32> = Mutex::new(1.0);
 pub static SIN_ANGLE: Mutex<f32> = Mutex::new(0.0);
+pub static ORIENTATION: Mutex<[f32; 4]> = Mutex::new([1.0, 0.0, 0.0, 0.0]);
+pub static HEIGHT_SHIFT: Mutex<f32> = Mutex::new(-2.0);
+pub static UP_AXIS: Mutex<u32> = Mutex::new(UP_AXIS_Z);
+pub static COEFF_EASING: Mutex<CoeffEasing> = Mutex::new(CoeffEasing::new());
 
 pub const PARABOLOID_WIDTH: f32 = 2.0;
 pub const PARABOLOID_HEIGHT: f32 = 2.0;
@@ -43,24 +93,583 @@ pub const PARABOLOID_HEIGHT: f32 = 2.0;
 pub const PARABOLOID_X_START: f32 = -1.0;
 pub const PARABOLOID_Y_START: f32 = -1.0;
 
-pub static MESH_BUFFER: Mutex<[f32; MESH_SIZE]> = Mutex::new([0.0; MESH_SIZE]);
-pub static INDEX_BUFFER: Mutex<[u32; INDEX_SIZE]> = Mutex::new([0; INDEX_SIZE]);
+pub const HELICOID_U_START: f32 = -1.0;
+pub const HELICOID_U_WIDTH: f32 = 2.0;
+pub const HELICOID_TURNS: f32 = 1.0;
+
+pub const CATENOID_U_START: f32 = -1.0;
+pub const CATENOID_U_WIDTH: f32 = 2.0;
+
+pub const KLEIN_RADIUS: f32 = 2.0;
+
+pub const DINI_U_WIDTH: f32 = 4.0 * std::f32::consts::PI;
+pub const DINI_V_START: f32 = 0.1;
+pub const DINI_V_END: f32 = 2.0;
+
+pub const BREATHER_U_RANGE: f32 = 14.0;
+pub const BREATHER_V_RANGE: f32 = 37.4;
 
+pub const AREA_EPSILON: f32 = 1.0E-12;
+
+pub static BUFFER_DIMENSIONS: Mutex<[u32; 2]> =
+    Mutex::new([MAX_WIDTH, MAX_HEIGHT]);
+pub static MESH_BUFFER: Mutex<Vec<f32>> = Mutex::new(Vec::new());
+pub static MESH_BUFFER_BACK: Mutex<Vec<f32>> = Mutex::new(Vec::new());
+pub static MESH_BUFFERS_SWAPPED: AtomicBool = AtomicBool::new(false);
+pub static INDEX_BUFFER: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+pub static TRI_INDEX_BUFFER: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+pub static PERIODIC_INDEX_BUFFER: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+pub static NORMAL_BUFFER: Mutex<Vec<f32>> = Mutex::new(Vec::new());
+pub static COLOR_BUFFER: Mutex<Vec<f32>> = Mutex::new(Vec::new());
+pub static UV_BUFFER: Mutex<Vec<f32>> = Mutex::new(Vec::new());
+pub static CURVATURE_BUFFER: Mutex<Vec<f32>> = Mutex::new(Vec::new());
+pub static INDEX_CACHE: Mutex<Option<(usize, u32, u32)>> = Mutex::new(None);
+
+/*  Unit tests that use the globals hold this lock, so they never run at the  *
+ *  same time and change each other's settings.                               */
+#[cfg(test)]
+pub static TEST_LOCK: Mutex<()> = Mutex::new(());
+
+pub mod advance_rotation;
+pub mod apply_orientation;
+pub mod buffer_capacity;
+pub mod build_surface;
+pub mod checked_len;
+pub mod clear_all;
+pub mod clear_index_buffer;
+pub mod clear_mesh_buffer;
+pub mod clip_mesh;
+pub mod coeff_easing;
+pub mod dimension_status;
+pub mod export_obj;
+pub mod export_stl;
+pub mod fast_rsqrt;
+pub mod generate_breather;
+pub mod generate_bumps;
+pub mod generate_catenoid;
+pub mod generate_colors;
+pub mod generate_cone;
+pub mod generate_contours;
+pub mod generate_cross_section;
+pub mod generate_curvature;
+pub mod generate_cylinder;
+pub mod generate_dini;
+pub mod generate_edge_colors;
+pub mod generate_enneper;
+pub mod generate_face_normals;
+pub mod generate_gradient;
+pub mod generate_helicoid;
 pub mod generate_indices;
+pub mod generate_indices_cached;
+pub mod generate_indices_colmajor;
+pub mod generate_indices_cylinder;
+pub mod generate_indices_diagonal;
+pub mod generate_indices_disk;
+pub mod generate_indices_grid;
+pub mod generate_indices_lod;
+pub mod generate_indices_loop;
+pub mod generate_indices_periodic;
+pub mod generate_indices_seam;
+pub mod generate_indices_u16;
+pub mod generate_indices_wrapped;
+pub mod generate_klein;
 pub mod generate_mesh;
+pub mod generate_mesh_adaptive;
+pub mod generate_mesh_chunk;
+pub mod generate_mesh_colmajor;
+pub mod generate_mesh_disk;
+pub mod generate_mesh_domain;
+pub mod generate_mesh_generic;
+pub mod generate_mesh_grid;
+pub mod generate_mesh_hyperbolic;
+pub mod generate_mesh_into_static;
+pub mod generate_mesh_monkey_saddle;
+pub mod generate_mesh_morph;
+pub mod generate_mesh_with_normals;
+pub mod generate_mobius;
+pub mod generate_normals;
+pub mod generate_revolution;
+pub mod generate_ripple;
+pub mod generate_roman;
+pub mod generate_sph_harmonic;
+pub mod generate_sphere;
+pub mod generate_superellipsoid;
+pub mod generate_thick_wireframe;
+pub mod generate_torus;
+pub mod generate_torus_knot;
+pub mod generate_triangle_indices;
+pub mod generate_uvs;
+pub mod get_back_mesh_buffer;
+pub mod get_coeffs;
+pub mod get_color_buffer;
+pub mod get_cos_sin;
+pub mod get_curvature_buffer;
 pub mod get_index_buffer;
 pub mod get_mesh_buffer;
+pub mod get_normal_buffer;
+pub mod get_periodic_index_buffer;
+pub mod get_rotation_angle;
+pub mod get_triangle_index_buffer;
+pub mod get_uv_buffer;
+pub mod index_count;
+pub mod init_buffers;
+pub mod init_panic_hook;
+pub mod invalidate_index_cache;
+pub mod load_mesh_bytes;
+pub mod lock;
+pub mod memory_layout;
+pub mod mesh_bounding_box;
+pub mod mesh_buffer_view;
+pub mod mesh_bytes;
+pub mod mesh_centroid;
+pub mod mesh_delta;
+pub mod mesh_surface_area;
+pub mod mesh_volume;
+pub mod mesh_z_range;
+pub mod morph_catenoid_helicoid;
+pub mod normal_curvature;
+pub mod periodic_index_count;
+pub mod project_mesh;
+pub mod quantize_mesh;
+pub mod rotate_by;
 pub mod rotate_mesh;
+pub mod rotate_mesh_axis;
+pub mod rotate_mesh_euler;
+#[cfg(any(test, all(feature = "wasm-simd",
+                   target_arch = "wasm32", target_feature = "simd128")))]
+pub mod rotate_mesh_simd;
+pub mod rotate_mesh_x;
+pub mod rotate_mesh_y;
+pub mod rotate_orientation;
+pub mod scale_mesh;
+pub mod scale_mesh_uniform;
+pub mod set_coeff_target;
+pub mod set_height_shift;
 pub mod set_rotation_angle;
+pub mod set_up_axis;
+pub mod shear_mesh;
+pub mod subdivide_mesh;
+pub mod surface;
+pub mod swap_mesh_buffers;
+pub mod tick_coeffs;
+pub mod translate_mesh;
+pub mod triangle_index_count;
+pub mod up_axis;
+pub mod update_z;
+pub mod update_z_eased;
+pub mod uv_float_count;
+pub mod validate_winding;
+pub mod vertex_float_count;
+pub mod weld_mesh;
+
+#[wasm_bindgen(js_name = "advanceRotation")]
+pub fn wasm_advance_rotation(dt_seconds: f32, radians_per_second: f32) {
+    advance_rotation::advance_rotation(dt_seconds, radians_per_second);
+}
+
+#[wasm_bindgen(js_name = "applyOrientation")]
+pub fn wasm_apply_orientation(ptr: *mut f32, n_pts: u32) {
+    apply_orientation::apply_orientation(ptr, n_pts);
+}
+
+#[wasm_bindgen(js_name = "buildSurface")]
+pub fn wasm_build_surface(nx_pts: u32,
+                          ny_pts: u32) -> build_surface::SurfaceHandle {
+    return build_surface::build_surface(nx_pts, ny_pts);
+}
+
+#[wasm_bindgen(js_name = "clearAll")]
+pub fn wasm_clear_all() {
+    clear_all::clear_all();
+}
+
+#[wasm_bindgen(js_name = "clearIndexBuffer")]
+pub fn wasm_clear_index_buffer() {
+    clear_index_buffer::clear_index_buffer();
+}
+
+#[wasm_bindgen(js_name = "clearMeshBuffer")]
+pub fn wasm_clear_mesh_buffer() {
+    clear_mesh_buffer::clear_mesh_buffer();
+}
+
+#[wasm_bindgen(js_name = "clipMesh")]
+pub fn wasm_clip_mesh(ptr: *mut f32, n_pts: u32,
+                      nx: f32, ny: f32, nz: f32, d: f32) -> u32 {
+    return clip_mesh::clip_mesh(ptr, n_pts, nx, ny, nz, d);
+}
+
+#[wasm_bindgen(js_name = "exportObj")]
+pub fn wasm_export_obj(nx_pts: u32, ny_pts: u32) -> String {
+    return export_obj::export_obj(nx_pts, ny_pts);
+}
+
+#[wasm_bindgen(js_name = "exportStl")]
+pub fn wasm_export_stl(nx_pts: u32, ny_pts: u32) -> Vec<u8> {
+    return export_stl::export_stl(nx_pts, ny_pts);
+}
+
+#[wasm_bindgen(js_name = "floatBytes")]
+pub fn wasm_float_bytes() -> u32 {
+    return memory_layout::float_bytes();
+}
+
+#[wasm_bindgen(js_name = "generateBreather")]
+pub fn wasm_generate_breather(ptr: *mut f32, n_u: u32, n_v: u32, aa: f32) {
+    generate_breather::generate_breather(ptr, n_u, n_v, aa);
+}
+
+#[wasm_bindgen(js_name = "generateBumps")]
+pub fn wasm_generate_bumps(ptr: *mut f32, nx_pts: u32, ny_pts: u32,
+                           centers_ptr: *const f32, n_centers: u32) {
+    generate_bumps::generate_bumps(ptr, nx_pts, ny_pts, centers_ptr, n_centers);
+}
+
+#[wasm_bindgen(js_name = "generateCatenoid")]
+pub fn wasm_generate_catenoid(ptr: *mut f32, n_u: u32, n_v: u32, c: f32) {
+    generate_catenoid::generate_catenoid(ptr, n_u, n_v, c);
+}
+
+#[wasm_bindgen(js_name = "generateColors")]
+pub fn wasm_generate_colors(ptr: *mut f32, nx_pts: u32, ny_pts: u32) {
+    generate_colors::generate_colors(ptr, nx_pts, ny_pts);
+}
+
+#[wasm_bindgen(js_name = "generateCone")]
+pub fn wasm_generate_cone(ptr: *mut f32, n_theta: u32, n_height: u32,
+                          radius: f32, height: f32) {
+    generate_cone::generate_cone(ptr, n_theta, n_height, radius, height);
+}
+
+#[wasm_bindgen(js_name = "generateContours")]
+pub fn wasm_generate_contours(ptr: *mut f32, len: u32, nx_pts: u32,
+                              ny_pts: u32, levels: &[f32]) -> u32 {
+    return generate_contours::generate_contours(
+        ptr, len, nx_pts, ny_pts, levels
+    );
+}
+
+#[wasm_bindgen(js_name = "generateCrossSection")]
+pub fn wasm_generate_cross_section(nx_pts: u32, ny_pts: u32, plane_axis: u32,
+                                   plane_value: f32, out_ptr: *mut f32) -> u32 {
+    return generate_cross_section::generate_cross_section(
+        nx_pts, ny_pts, plane_axis, plane_value, out_ptr
+    );
+}
+
+#[wasm_bindgen(js_name = "generateCurvature")]
+pub fn wasm_generate_curvature(ptr: *mut f32, nx_pts: u32, ny_pts: u32) {
+    generate_curvature::generate_curvature(ptr, nx_pts, ny_pts);
+}
+
+#[wasm_bindgen(js_name = "generateCylinder")]
+pub fn wasm_generate_cylinder(ptr: *mut f32, n_theta: u32, n_height: u32,
+                              radius: f32, height: f32, capped: bool) -> u32 {
+    return generate_cylinder::generate_cylinder(ptr, n_theta, n_height,
+                                                radius, height, capped);
+}
+
+#[wasm_bindgen(js_name = "generateDini")]
+pub fn wasm_generate_dini(ptr: *mut f32, n_u: u32, n_v: u32, a: f32, b: f32) {
+    generate_dini::generate_dini(ptr, n_u, n_v, a, b);
+}
+
+#[wasm_bindgen(js_name = "generateEdgeColors")]
+pub fn wasm_generate_edge_colors(ptr: *mut f32, nx_pts: u32, ny_pts: u32) {
+    generate_edge_colors::generate_edge_colors(ptr, nx_pts, ny_pts);
+}
+
+#[wasm_bindgen(js_name = "generateEnneper")]
+pub fn wasm_generate_enneper(ptr: *mut f32, n_u: u32, n_v: u32, range: f32) {
+    generate_enneper::generate_enneper(ptr, n_u, n_v, range);
+}
+
+#[wasm_bindgen(js_name = "generateFaceNormals")]
+pub fn wasm_generate_face_normals(ptr: *mut f32, nx_pts: u32, ny_pts: u32) {
+    generate_face_normals::generate_face_normals(ptr, nx_pts, ny_pts);
+}
+
+#[wasm_bindgen(js_name = "generateGradient")]
+pub fn wasm_generate_gradient(ptr: *mut f32, nx_pts: u32, ny_pts: u32) {
+    generate_gradient::generate_gradient(ptr, nx_pts, ny_pts);
+}
+
+#[wasm_bindgen(js_name = "generateHelicoid")]
+pub fn wasm_generate_helicoid(ptr: *mut f32, n_u: u32, n_v: u32, c: f32) {
+    generate_helicoid::generate_helicoid(ptr, n_u, n_v, c);
+}
 
 #[wasm_bindgen(js_name = "generateIndices")]
-pub fn wasm_generate_indices(ptr: *mut u32, nx_pts: u32, ny_pts: u32) {
-    generate_indices::generate_indices(ptr, nx_pts, ny_pts);
+pub fn wasm_generate_indices(ptr: *mut u32, nx_pts: u32, ny_pts: u32) -> i32 {
+    return generate_indices::generate_indices(ptr, nx_pts, ny_pts);
+}
+
+#[wasm_bindgen(js_name = "generateIndicesCached")]
+pub fn wasm_generate_indices_cached(ptr: *mut u32,
+                                    nx_pts: u32, ny_pts: u32) -> i32 {
+    return generate_indices_cached::generate_indices_cached(ptr, nx_pts,
+                                                            ny_pts);
+}
+
+#[wasm_bindgen(js_name = "generateIndicesColmajor")]
+pub fn wasm_generate_indices_colmajor(ptr: *mut u32,
+                                      nx_pts: u32, ny_pts: u32) -> i32 {
+    return generate_indices_colmajor::generate_indices_colmajor(ptr, nx_pts,
+                                                                ny_pts);
+}
+
+#[wasm_bindgen(js_name = "generateIndicesCylinder")]
+pub fn wasm_generate_indices_cylinder(ptr: *mut u32, n_theta: u32,
+                                      n_height: u32, capped: bool) -> u32 {
+    return generate_indices_cylinder::generate_indices_cylinder(
+        ptr, n_theta, n_height, capped
+    );
+}
+
+#[wasm_bindgen(js_name = "generateIndicesDiagonal")]
+pub fn wasm_generate_indices_diagonal(ptr: *mut u32,
+                                      nx_pts: u32, ny_pts: u32) {
+    generate_indices_diagonal::generate_indices_diagonal(ptr, nx_pts, ny_pts);
+}
+
+#[wasm_bindgen(js_name = "generateIndicesDisk")]
+pub fn wasm_generate_indices_disk(ptr: *mut u32,
+                                  n_r: u32, n_theta: u32) -> u32 {
+    return generate_indices_disk::generate_indices_disk(ptr, n_r, n_theta);
+}
+
+#[wasm_bindgen(js_name = "generateIndicesGrid")]
+pub fn wasm_generate_indices_grid(ptr: *mut u32, nx_pts: u32, ny_pts: u32,
+                                  cols: u32, rows: u32) -> u32 {
+    return generate_indices_grid::generate_indices_grid(ptr, nx_pts, ny_pts,
+                                                        cols, rows);
+}
+
+#[wasm_bindgen(js_name = "generateIndicesLod")]
+pub fn wasm_generate_indices_lod(ptr: *mut u32, nx_pts: u32, ny_pts: u32,
+                                 stride: u32) -> u32 {
+    return generate_indices_lod::generate_indices_lod(ptr, nx_pts, ny_pts,
+                                                      stride);
+}
+
+#[wasm_bindgen(js_name = "generateIndicesLoop")]
+pub fn wasm_generate_indices_loop(ptr: *mut u32, n_pts: u32) -> u32 {
+    return generate_indices_loop::generate_indices_loop(ptr, n_pts);
+}
+
+#[wasm_bindgen(js_name = "generateIndicesPeriodic")]
+pub fn wasm_generate_indices_periodic(ptr: *mut u32, nx_pts: u32, ny_pts: u32,
+                                      wrap_x: bool, wrap_y: bool, flip: bool) {
+    generate_indices_periodic::generate_indices_periodic(ptr, nx_pts, ny_pts,
+                                                         wrap_x, wrap_y, flip);
+}
+
+#[wasm_bindgen(js_name = "generateIndicesSeam")]
+pub fn wasm_generate_indices_seam(ptr: *mut u32, nx_pts: u32, ny_pts: u32,
+                                  wrap: bool, flip: bool) {
+    generate_indices_seam::generate_indices_seam(ptr, nx_pts, ny_pts,
+                                                 wrap, flip);
+}
+
+#[wasm_bindgen(js_name = "generateIndicesU16")]
+pub fn wasm_generate_indices_u16(ptr: *mut u16,
+                                 nx_pts: u32, ny_pts: u32) -> i32 {
+    return generate_indices_u16::generate_indices_u16(ptr, nx_pts, ny_pts);
+}
+
+#[wasm_bindgen(js_name = "generateIndicesWrapped")]
+pub fn wasm_generate_indices_wrapped(ptr: *mut u32, nx_pts: u32,
+                                     ny_pts: u32, wrap: bool) {
+    generate_indices_wrapped::generate_indices_wrapped(ptr, nx_pts,
+                                                       ny_pts, wrap);
+}
+
+#[wasm_bindgen(js_name = "generateKlein")]
+pub fn wasm_generate_klein(ptr: *mut f32, n_u: u32, n_v: u32) {
+    generate_klein::generate_klein(ptr, n_u, n_v);
 }
 
 #[wasm_bindgen(js_name = "generateMesh")]
-pub fn wasm_generate_mesh(ptr: *mut f32, nx_pts: u32, ny_pts: u32) {
-    generate_mesh::generate_mesh(ptr, nx_pts, ny_pts);
+pub fn wasm_generate_mesh(ptr: *mut f32, nx_pts: u32, ny_pts: u32) -> i32 {
+    return generate_mesh::generate_mesh(ptr, nx_pts, ny_pts);
+}
+
+#[wasm_bindgen(js_name = "generateMeshAdaptive")]
+pub fn wasm_generate_mesh_adaptive(mesh_ptr: *mut f32, index_ptr: *mut u32,
+                                   nx_pts: u32, ny_pts: u32,
+                                   curvature_threshold: f32) -> Box<[u32]> {
+    return Box::new(generate_mesh_adaptive::generate_mesh_adaptive(
+        mesh_ptr, index_ptr, nx_pts, ny_pts, curvature_threshold
+    ));
+}
+
+#[wasm_bindgen(js_name = "generateMeshChunk")]
+pub fn wasm_generate_mesh_chunk(ptr: *mut f32, nx_pts: u32, ny_pts: u32,
+                                row_start: u32, row_count: u32) -> u32 {
+    return generate_mesh_chunk::generate_mesh_chunk(ptr, nx_pts, ny_pts,
+                                                    row_start, row_count);
+}
+
+#[wasm_bindgen(js_name = "generateMeshColmajor")]
+pub fn wasm_generate_mesh_colmajor(ptr: *mut f32,
+                                   nx_pts: u32, ny_pts: u32) -> i32 {
+    return generate_mesh_colmajor::generate_mesh_colmajor(ptr, nx_pts, ny_pts);
+}
+
+#[wasm_bindgen(js_name = "generateMeshDisk")]
+pub fn wasm_generate_mesh_disk(ptr: *mut f32, n_r: u32, n_theta: u32,
+                               a: f32, b: f32) -> u32 {
+    return generate_mesh_disk::generate_mesh_disk(ptr, n_r, n_theta, a, b);
+}
+
+#[wasm_bindgen(js_name = "generateMeshDomain")]
+pub fn wasm_generate_mesh_domain(ptr: *mut f32, nx_pts: u32, ny_pts: u32,
+                                 x0: f32, x1: f32,
+                                 y0: f32, y1: f32) -> i32 {
+    return generate_mesh_domain::generate_mesh_domain(ptr, nx_pts, ny_pts,
+                                                      x0, x1, y0, y1);
+}
+
+#[wasm_bindgen(js_name = "generateMeshGrid")]
+pub fn wasm_generate_mesh_grid(ptr: *mut f32, nx_pts: u32, ny_pts: u32,
+                               cols: u32, rows: u32, spacing: f32) -> u32 {
+    return generate_mesh_grid::generate_mesh_grid(ptr, nx_pts, ny_pts,
+                                                  cols, rows, spacing);
+}
+
+#[wasm_bindgen(js_name = "generateMeshHyperbolic")]
+pub fn wasm_generate_mesh_hyperbolic(ptr: *mut f32, nx_pts: u32, ny_pts: u32) {
+    generate_mesh_hyperbolic::generate_mesh_hyperbolic(ptr, nx_pts, ny_pts);
+}
+
+#[wasm_bindgen(js_name = "generateMeshIntoStatic")]
+pub fn wasm_generate_mesh_into_static(nx_pts: u32, ny_pts: u32) {
+    generate_mesh_into_static::generate_mesh_into_static(nx_pts, ny_pts);
+}
+
+#[wasm_bindgen(js_name = "generateMeshMonkeySaddle")]
+pub fn wasm_generate_mesh_monkey_saddle(ptr: *mut f32,
+                                        nx_pts: u32, ny_pts: u32) {
+    generate_mesh_monkey_saddle::generate_mesh_monkey_saddle(ptr, nx_pts,
+                                                             ny_pts);
+}
+
+#[wasm_bindgen(js_name = "generateMeshMorph")]
+pub fn wasm_generate_mesh_morph(ptr: *mut f32, nx_pts: u32, ny_pts: u32,
+                                t: f32) {
+    generate_mesh_morph::generate_mesh_morph(ptr, nx_pts, ny_pts, t);
+}
+
+#[wasm_bindgen(js_name = "generateMeshWithNormals")]
+pub fn wasm_generate_mesh_with_normals(ptr: *mut f32,
+                                       nx_pts: u32, ny_pts: u32) {
+    generate_mesh_with_normals::generate_mesh_with_normals(ptr, nx_pts,
+                                                           ny_pts);
+}
+
+#[wasm_bindgen(js_name = "generateMobius")]
+pub fn wasm_generate_mobius(ptr: *mut f32, n_u: u32, n_v: u32, width: f32) {
+    generate_mobius::generate_mobius(ptr, n_u, n_v, width);
+}
+
+#[wasm_bindgen(js_name = "generateNormals")]
+pub fn wasm_generate_normals(ptr: *mut f32, nx_pts: u32, ny_pts: u32) {
+    generate_normals::generate_normals(ptr, nx_pts, ny_pts);
+}
+
+#[wasm_bindgen(js_name = "generateRevolution")]
+pub fn wasm_generate_revolution(ptr: *mut f32, n_theta: u32, n_s: u32,
+                                profile_ptr: *const f32, n_profile: u32) {
+    generate_revolution::generate_revolution(ptr, n_theta, n_s,
+                                             profile_ptr, n_profile);
+}
+
+#[wasm_bindgen(js_name = "generateRipple")]
+pub fn wasm_generate_ripple(ptr: *mut f32, nx_pts: u32, ny_pts: u32,
+                            t: f32, k: f32, amp: f32) {
+    generate_ripple::generate_ripple(ptr, nx_pts, ny_pts, t, k, amp);
+}
+
+#[wasm_bindgen(js_name = "generateRoman")]
+pub fn wasm_generate_roman(ptr: *mut f32, n_u: u32, n_v: u32, radius: f32) {
+    generate_roman::generate_roman(ptr, n_u, n_v, radius);
+}
+
+#[wasm_bindgen(js_name = "generateSphHarmonic")]
+pub fn wasm_generate_sph_harmonic(ptr: *mut f32, n_theta: u32, n_phi: u32,
+                                  l: u32, m: i32) {
+    generate_sph_harmonic::generate_sph_harmonic(ptr, n_theta, n_phi, l, m);
+}
+
+#[wasm_bindgen(js_name = "generateSphere")]
+pub fn wasm_generate_sphere(ptr: *mut f32, n_theta: u32, n_phi: u32,
+                            radius: f32) {
+    generate_sphere::generate_sphere(ptr, n_theta, n_phi, radius);
+}
+
+#[wasm_bindgen(js_name = "generateSuperellipsoid")]
+pub fn wasm_generate_superellipsoid(ptr: *mut f32, n_u: u32, n_v: u32,
+                                    e1: f32, e2: f32) {
+    generate_superellipsoid::generate_superellipsoid(ptr, n_u, n_v, e1, e2);
+}
+
+#[wasm_bindgen(js_name = "generateThickWireframe")]
+pub fn wasm_generate_thick_wireframe(vert_ptr: *mut f32, vert_len: u32,
+                                     index_ptr: *mut u32, index_len: u32,
+                                     nx_pts: u32, ny_pts: u32,
+                                     offset: f32) -> u32 {
+    return generate_thick_wireframe::generate_thick_wireframe(
+        vert_ptr, vert_len, index_ptr, index_len, nx_pts, ny_pts, offset
+    );
+}
+
+#[wasm_bindgen(js_name = "generateTorus")]
+pub fn wasm_generate_torus(ptr: *mut f32, n_u: u32, n_v: u32,
+                           major_r: f32, minor_r: f32) {
+    generate_torus::generate_torus(ptr, n_u, n_v, major_r, minor_r);
+}
+
+#[wasm_bindgen(js_name = "generateTorusKnot")]
+pub fn wasm_generate_torus_knot(ptr: *mut f32, n_pts: u32, p: u32, q: u32,
+                                major_r: f32, minor_r: f32) -> u32 {
+    return generate_torus_knot::generate_torus_knot(ptr, n_pts, p, q,
+                                                    major_r, minor_r);
+}
+
+#[wasm_bindgen(js_name = "generateTriangleIndices")]
+pub fn wasm_generate_triangle_indices(ptr: *mut u32,
+                                      nx_pts: u32, ny_pts: u32) {
+    generate_triangle_indices::generate_triangle_indices(ptr, nx_pts, ny_pts);
+}
+
+#[wasm_bindgen(js_name = "generateUvs")]
+pub fn wasm_generate_uvs(nx_pts: u32, ny_pts: u32) {
+    generate_uvs::generate_uvs(nx_pts, ny_pts);
+}
+
+#[wasm_bindgen(js_name = "getBackMeshBuffer")]
+pub fn wasm_get_back_mesh_buffer() -> usize {
+    return get_back_mesh_buffer::get_back_mesh_buffer();
+}
+
+#[wasm_bindgen(js_name = "getCoeffs")]
+pub fn wasm_get_coeffs() -> Box<[f32]> {
+    return Box::new(get_coeffs::get_coeffs());
+}
+
+#[wasm_bindgen(js_name = "getColorBuffer")]
+pub fn wasm_get_color_buffer() -> usize {
+    return get_color_buffer::get_color_buffer();
+}
+
+#[wasm_bindgen(js_name = "getCosSin")]
+pub fn wasm_get_cos_sin() -> Box<[f32]> {
+    return Box::new(get_cos_sin::get_cos_sin());
+}
+
+#[wasm_bindgen(js_name = "getCurvatureBuffer")]
+pub fn wasm_get_curvature_buffer() -> usize {
+    return get_curvature_buffer::get_curvature_buffer();
 }
 
 #[wasm_bindgen(js_name = "getIndexBuffer")]
@@ -73,12 +682,255 @@ pub fn wasm_get_mesh_buffer() -> usize {
     return get_mesh_buffer::get_mesh_buffer();
 }
 
+#[wasm_bindgen(js_name = "getNormalBuffer")]
+pub fn wasm_get_normal_buffer() -> usize {
+    return get_normal_buffer::get_normal_buffer();
+}
+
+#[wasm_bindgen(js_name = "getPeriodicIndexBuffer")]
+pub fn wasm_get_periodic_index_buffer() -> usize {
+    return get_periodic_index_buffer::get_periodic_index_buffer();
+}
+
+#[wasm_bindgen(js_name = "getRotationAngle")]
+pub fn wasm_get_rotation_angle() -> f32 {
+    return get_rotation_angle::get_rotation_angle();
+}
+
+#[wasm_bindgen(js_name = "getTriangleIndexBuffer")]
+pub fn wasm_get_triangle_index_buffer() -> usize {
+    return get_triangle_index_buffer::get_triangle_index_buffer();
+}
+
+#[wasm_bindgen(js_name = "getUvBuffer")]
+pub fn wasm_get_uv_buffer() -> usize {
+    return get_uv_buffer::get_uv_buffer();
+}
+
+#[wasm_bindgen(js_name = "indexCount")]
+pub fn wasm_index_count(nx_pts: u32, ny_pts: u32) -> u32 {
+    return index_count::index_count(nx_pts, ny_pts);
+}
+
+#[wasm_bindgen(js_name = "initBuffers")]
+pub fn wasm_init_buffers(max_w: u32, max_h: u32) -> i32 {
+    return init_buffers::init_buffers(max_w, max_h);
+}
+
+#[wasm_bindgen(js_name = "initPanicHook")]
+pub fn wasm_init_panic_hook() {
+    init_panic_hook::init_panic_hook();
+}
+
+#[wasm_bindgen(js_name = "invalidateIndexCache")]
+pub fn wasm_invalidate_index_cache() {
+    invalidate_index_cache::invalidate_index_cache();
+}
+
+#[wasm_bindgen(js_name = "loadMeshBytes")]
+pub fn wasm_load_mesh_bytes(bytes: &[u8]) -> i32 {
+    return load_mesh_bytes::load_mesh_bytes(bytes);
+}
+
+#[wasm_bindgen(js_name = "maxHeight")]
+pub fn wasm_max_height() -> u32 {
+    return memory_layout::max_height();
+}
+
+#[wasm_bindgen(js_name = "maxWidth")]
+pub fn wasm_max_width() -> u32 {
+    return memory_layout::max_width();
+}
+
+#[wasm_bindgen(js_name = "meshBoundingBox")]
+pub fn wasm_mesh_bounding_box(ptr: *const f32, n_pts: u32) -> Box<[f32]> {
+    return Box::new(mesh_bounding_box::mesh_bounding_box(ptr, n_pts));
+}
+
+#[wasm_bindgen(js_name = "meshBufferView")]
+pub fn wasm_mesh_buffer_view(nx_pts: u32, ny_pts: u32) -> Vec<f32> {
+    return mesh_buffer_view::mesh_buffer_view(nx_pts, ny_pts);
+}
+
+#[wasm_bindgen(js_name = "meshBytes")]
+pub fn wasm_mesh_bytes(nx_pts: u32, ny_pts: u32) -> Vec<u8> {
+    return mesh_bytes::mesh_bytes(nx_pts, ny_pts);
+}
+
+#[wasm_bindgen(js_name = "meshCentroid")]
+pub fn wasm_mesh_centroid(ptr: *const f32, n_pts: u32) -> Box<[f32]> {
+    return Box::new(mesh_centroid::mesh_centroid(ptr, n_pts));
+}
+
+#[wasm_bindgen(js_name = "meshDelta")]
+pub fn wasm_mesh_delta(prev_ptr: *const f32, curr_ptr: *const f32,
+                       out_ptr: *mut f32, n_pts: u32) {
+    mesh_delta::mesh_delta(prev_ptr, curr_ptr, out_ptr, n_pts);
+}
+
+#[wasm_bindgen(js_name = "meshSurfaceArea")]
+pub fn wasm_mesh_surface_area(ptr: *const f32, nx_pts: u32, ny_pts: u32,
+                              eps: f32) -> f32 {
+    return mesh_surface_area::mesh_surface_area(ptr, nx_pts, ny_pts, eps);
+}
+
+#[wasm_bindgen(js_name = "meshVolume")]
+pub fn wasm_mesh_volume(nx_pts: u32, ny_pts: u32, a: f32, b: f32) -> f32 {
+    return mesh_volume::mesh_volume(nx_pts, ny_pts, a, b);
+}
+
+#[wasm_bindgen(js_name = "meshZRange")]
+pub fn wasm_mesh_z_range(ptr: *const f32, n_pts: u32) -> Box<[f32]> {
+    return Box::new(mesh_z_range::mesh_z_range(ptr, n_pts));
+}
+
+#[wasm_bindgen(js_name = "morphCatenoidHelicoid")]
+pub fn wasm_morph_catenoid_helicoid(ptr: *mut f32, n_u: u32, n_v: u32,
+                                    c: f32, t: f32) {
+    morph_catenoid_helicoid::morph_catenoid_helicoid(ptr, n_u, n_v, c, t);
+}
+
+#[wasm_bindgen(js_name = "normalCurvature")]
+pub fn wasm_normal_curvature(x: f32, y: f32, dir_x: f32, dir_y: f32,
+                             a: f32, b: f32) -> f32 {
+    return normal_curvature::normal_curvature(x, y, dir_x, dir_y, a, b);
+}
+
+#[wasm_bindgen(js_name = "projectMesh")]
+pub fn wasm_project_mesh(ptr: *mut f32, n_pts: u32,
+                         fov: f32, aspect: f32, near: f32, far: f32) {
+    project_mesh::project_mesh(ptr, n_pts, fov, aspect, near, far);
+}
+
+#[wasm_bindgen(js_name = "quantizeMesh")]
+pub fn wasm_quantize_mesh(ptr: *mut f32, n_pts: u32, step: f32) {
+    quantize_mesh::quantize_mesh(ptr, n_pts, step);
+}
+
+#[wasm_bindgen(js_name = "rotateBy")]
+pub fn wasm_rotate_by(delta: f32) {
+    rotate_by::rotate_by(delta);
+}
+
 #[wasm_bindgen(js_name = "rotateMesh")]
 pub fn wasm_rotate_mesh(ptr: *mut f32, n_pts: u32) {
     rotate_mesh::rotate_mesh(ptr, n_pts);
 }
 
+#[wasm_bindgen(js_name = "rotateMeshAxis")]
+pub fn wasm_rotate_mesh_axis(ptr: *mut f32, n_pts: u32,
+                             ax: f32, ay: f32, az: f32) {
+    rotate_mesh_axis::rotate_mesh_axis(ptr, n_pts, ax, ay, az);
+}
+
+#[wasm_bindgen(js_name = "rotateMeshEuler")]
+pub fn wasm_rotate_mesh_euler(ptr: *mut f32, n_pts: u32,
+                              yaw: f32, pitch: f32, roll: f32) {
+    rotate_mesh_euler::rotate_mesh_euler(ptr, n_pts, yaw, pitch, roll);
+}
+
+#[wasm_bindgen(js_name = "rotateMeshX")]
+pub fn wasm_rotate_mesh_x(ptr: *mut f32, n_pts: u32) {
+    rotate_mesh_x::rotate_mesh_x(ptr, n_pts);
+}
+
+#[wasm_bindgen(js_name = "rotateMeshY")]
+pub fn wasm_rotate_mesh_y(ptr: *mut f32, n_pts: u32) {
+    rotate_mesh_y::rotate_mesh_y(ptr, n_pts);
+}
+
+#[wasm_bindgen(js_name = "rotateOrientation")]
+pub fn wasm_rotate_orientation(ax: f32, ay: f32, az: f32, delta: f32) {
+    rotate_orientation::rotate_orientation(ax, ay, az, delta);
+}
+
+#[wasm_bindgen(js_name = "scaleMesh")]
+pub fn wasm_scale_mesh(ptr: *mut f32, n_pts: u32, sx: f32, sy: f32, sz: f32) {
+    scale_mesh::scale_mesh(ptr, n_pts, sx, sy, sz);
+}
+
+#[wasm_bindgen(js_name = "scaleMeshUniform")]
+pub fn wasm_scale_mesh_uniform(ptr: *mut f32, n_pts: u32, s: f32) {
+    scale_mesh_uniform::scale_mesh_uniform(ptr, n_pts, s);
+}
+
+#[wasm_bindgen(js_name = "setCoeffTarget")]
+pub fn wasm_set_coeff_target(a: f32, b: f32, duration: f32) {
+    set_coeff_target::set_coeff_target(a, b, duration);
+}
+
+#[wasm_bindgen(js_name = "setHeightShift")]
+pub fn wasm_set_height_shift(shift: f32) {
+    set_height_shift::set_height_shift(shift);
+}
+
 #[wasm_bindgen(js_name = "setRotationAngle")]
 pub fn wasm_set_rotation_angle(angle: f32) {
     set_rotation_angle::set_rotation_angle(angle);
 }
+
+#[wasm_bindgen(js_name = "setUpAxis")]
+pub fn wasm_set_up_axis(axis: u32) -> i32 {
+    return set_up_axis::set_up_axis(axis);
+}
+
+#[wasm_bindgen(js_name = "shearMesh")]
+pub fn wasm_shear_mesh(ptr: *mut f32, n_pts: u32,
+                       shear_xy: f32, shear_xz: f32, shear_yz: f32) {
+    shear_mesh::shear_mesh(ptr, n_pts, shear_xy, shear_xz, shear_yz);
+}
+
+#[wasm_bindgen(js_name = "subdivideMesh")]
+pub fn wasm_subdivide_mesh(mesh_ptr: *mut f32, index_ptr: *mut u32,
+                           n_tris: u32) -> Box<[u32]> {
+    return Box::new(subdivide_mesh::subdivide_mesh(mesh_ptr, index_ptr,
+                                                   n_tris));
+}
+
+#[wasm_bindgen(js_name = "swapMeshBuffers")]
+pub fn wasm_swap_mesh_buffers() {
+    swap_mesh_buffers::swap_mesh_buffers();
+}
+
+#[wasm_bindgen(js_name = "tickCoeffs")]
+pub fn wasm_tick_coeffs(dt: f32) -> bool {
+    return tick_coeffs::tick_coeffs(dt);
+}
+
+#[wasm_bindgen(js_name = "translateMesh")]
+pub fn wasm_translate_mesh(ptr: *mut f32, n_pts: u32,
+                           dx: f32, dy: f32, dz: f32) {
+    translate_mesh::translate_mesh(ptr, n_pts, dx, dy, dz);
+}
+
+#[wasm_bindgen(js_name = "updateZ")]
+pub fn wasm_update_z(ptr: *mut f32, nx_pts: u32, ny_pts: u32, a: f32, b: f32) {
+    update_z::update_z(ptr, nx_pts, ny_pts, a, b);
+}
+
+#[wasm_bindgen(js_name = "updateZEased")]
+pub fn wasm_update_z_eased(ptr: *mut f32, nx_pts: u32, ny_pts: u32) {
+    update_z_eased::update_z_eased(ptr, nx_pts, ny_pts);
+}
+
+#[wasm_bindgen(js_name = "validateWinding")]
+pub fn wasm_validate_winding(mesh_ptr: *const f32, index_ptr: *const u32,
+                             tri_count: u32) -> f32 {
+    return validate_winding::validate_winding(mesh_ptr, index_ptr, tri_count);
+}
+
+#[wasm_bindgen(js_name = "vertexFloatCount")]
+pub fn wasm_vertex_float_count(nx_pts: u32, ny_pts: u32) -> u32 {
+    return vertex_float_count::vertex_float_count(nx_pts, ny_pts);
+}
+
+#[wasm_bindgen(js_name = "vertexStride")]
+pub fn wasm_vertex_stride() -> u32 {
+    return memory_layout::vertex_stride();
+}
+
+#[wasm_bindgen(js_name = "weldMesh")]
+pub fn wasm_weld_mesh(mesh_ptr: *mut f32, index_ptr: *mut u32,
+                      n_pts: u32, n_indices: u32, eps: f32) -> u32 {
+    return weld_mesh::weld_mesh(mesh_ptr, index_ptr, n_pts, n_indices, eps);
+}
@@ -0,0 +1,129 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Loads a mesh saved by mesh_bytes into the mesh buffer.                *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Number of f32's per point in the mesh.                                    */
+pub use crate::VERTEX_STRIDE;
+
+/*  Status codes returned to JavaScript.                                      */
+use crate::{STATUS_INVALID, STATUS_OK};
+
+/*  Status for a mesh that is too wide or too tall.                           */
+use crate::dimension_status::dimension_status;
+
+/*  The two buffers for the mesh, and which of the two is in front.           */
+use crate::{MESH_BUFFER, MESH_BUFFER_BACK, MESH_BUFFERS_SWAPPED};
+
+/*  Used for reading the flag set by swap_mesh_buffers.                       */
+use std::sync::atomic::Ordering;
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  The current capacity of the buffers, and allocation on first use.         */
+use crate::buffer_capacity::{buffer_capacity, reserve_buffer};
+
+/*  Number of elements in the vertex array for the given dimensions.          */
+use crate::vertex_float_count::vertex_float_count;
+
+/*  Description of the format written by mesh_bytes.                          */
+use crate::mesh_bytes::{MESH_BYTES_HEADER_LEN, MESH_BYTES_MAGIC};
+
+/*  Function for forgetting the wireframe cached by generateIndicesCached.    */
+use crate::invalidate_index_cache::invalidate_index_cache;
+
+/*  Reads the little-endian u32 starting at the given offset. The caller has  *
+ *  already checked that the header is long enough.                           */
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    let mut word: [u8; 4] = [0; 4];
+    word.copy_from_slice(&bytes[offset..offset + 4]);
+    return u32::from_le_bytes(word);
+}
+/*  End of read_u32.                                                          */
+
+/*  Function for loading the bytes written by mesh_bytes into the front mesh  *
+ *  buffer, the one returned by get_mesh_buffer. The header is checked before *
+ *  anything is written, so a bad file leaves the buffer untouched. Returns   *
+ *  STATUS_OK on success, STATUS_TOO_WIDE or STATUS_TOO_TALL if the           *
+ *  dimensions do not fit in the buffer, and STATUS_INVALID if the bytes are  *
+ *  not a mesh in this format: a wrong magic or stride, or a length that does *
+ *  not match the dimensions in the header.                                   */
+pub fn load_mesh_bytes(bytes: &[u8]) -> i32 {
+
+    /*  Check that this is the right format before reading the dimensions.    */
+    if bytes.len() < MESH_BYTES_HEADER_LEN {
+        return STATUS_INVALID;
+    }
+
+    if bytes[0..4] != MESH_BYTES_MAGIC {
+        return STATUS_INVALID;
+    }
+
+    let nx_pts: u32 = read_u32(bytes, 4);
+    let ny_pts: u32 = read_u32(bytes, 8);
+    let stride: u32 = read_u32(bytes, 12);
+
+    /*  Only meshes with the same layout as the buffer can be loaded.         */
+    if stride != VERTEX_STRIDE {
+        return STATUS_INVALID;
+    }
+
+    /*  The static buffer only has room for the dimensions given to           *
+     *  init_buffers.                                                         */
+    let (width, height): (u32, u32) = buffer_capacity();
+
+    let status: i32 = dimension_status(nx_pts, ny_pts, width, height);
+
+    if status != STATUS_OK {
+        return status;
+    }
+
+    /*  The dimensions are bounded by the capacity, so this can not overflow. */
+    let len: usize = vertex_float_count(nx_pts, ny_pts) as usize;
+    let data: &[u8] = &bytes[MESH_BYTES_HEADER_LEN..];
+
+    /*  A truncated file, or one with extra data, is rejected.                */
+    if data.len() != len * std::mem::size_of::<f32>() {
+        return STATUS_INVALID;
+    }
+
+    /*  The loaded mesh may have other dimensions than the cached wireframe.  */
+    invalidate_index_cache();
+
+    /*  Write to whichever buffer is currently in front.                      */
+    let mut buffer = if MESH_BUFFERS_SWAPPED.load(Ordering::Acquire) {
+        lock(&MESH_BUFFER_BACK)
+    } else {
+        lock(&MESH_BUFFER)
+    };
+
+    reserve_buffer(&mut buffer, vertex_float_count(width, height) as usize);
+
+    for (value, chunk) in buffer.iter_mut().zip(data.chunks_exact(4)) {
+        let mut word: [u8; 4] = [0; 4];
+        word.copy_from_slice(chunk);
+        *value = f32::from_le_bytes(word);
+    }
+
+    return STATUS_OK;
+}
+/*  End of load_mesh_bytes.                                                   */
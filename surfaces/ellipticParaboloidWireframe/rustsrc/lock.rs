@@ -0,0 +1,68 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Locks one of the globals, recovering the data if the Mutex has been   *
+ *      poisoned.                                                             *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Synchonization primitive allowing safe access to shared data.             */
+use std::sync::{Mutex, MutexGuard, PoisonError, TryLockError};
+
+/*  Function for locking one of the globals without panicking.                */
+pub fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+
+    /*  A Mutex is poisoned if a previous call panicked while holding it. The *
+     *  data in the buffers is still usable, at worst one frame is bad, so    *
+     *  take the guard anyway instead of aborting every subsequent call.      */
+    return mutex.lock().unwrap_or_else(PoisonError::into_inner);
+}
+
+/*  Function for locking one of the globals, giving up if it is in use.       */
+pub fn try_lock<T>(mutex: &Mutex<T>) -> Option<MutexGuard<'_, T>> {
+
+    /*  A poisoned lock still holds usable data, so recover the guard from it.*/
+    return match mutex.try_lock() {
+        Ok(guard) => Some(guard),
+        Err(TryLockError::Poisoned(err)) => Some(err.into_inner()),
+        Err(TryLockError::WouldBlock) => None,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lock, try_lock};
+    use std::sync::Mutex;
+
+    /*  A second guard is refused while the first is alive, and allowed again *
+     *  once it is dropped.                                                   */
+    #[test]
+    fn try_lock_fails_while_borrowed() {
+        let global: Mutex<u32> = Mutex::new(1);
+        let guard = lock(&global);
+        assert!(try_lock(&global).is_none());
+        drop(guard);
+
+        let mut guard = lock(&global);
+        *guard += 1;
+        drop(guard);
+        assert_eq!(try_lock(&global).map(|guard| *guard), Some(2));
+    }
+}
+/*  End of lock.                                                              */
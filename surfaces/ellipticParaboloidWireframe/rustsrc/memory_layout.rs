@@ -0,0 +1,50 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Returns the sizes JavaScript needs for reading the buffers in wasm    *
+ *      memory.                                                               *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Number of f32's per vertex, and the number of bytes in an f32.            */
+use crate::{FLOAT_BYTES, VERTEX_STRIDE};
+
+/*  Maximum number of pixels in the vertical and horizontal axes.             */
+use crate::{MAX_HEIGHT, MAX_WIDTH};
+
+/*  Function for getting the number of f32's in a single vertex.              */
+pub fn vertex_stride() -> u32 {
+    return VERTEX_STRIDE;
+}
+
+/*  Function for getting the number of bytes in a single f32.                 */
+pub fn float_bytes() -> u32 {
+    return FLOAT_BYTES;
+}
+
+/*  Function for getting the largest number of points along the x axis.       */
+pub fn max_width() -> u32 {
+    return MAX_WIDTH;
+}
+
+/*  Function for getting the largest number of points along the y axis.       */
+pub fn max_height() -> u32 {
+    return MAX_HEIGHT;
+}
+/*  End of memory_layout.                                                     */
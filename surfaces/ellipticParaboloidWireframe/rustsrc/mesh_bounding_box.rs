@@ -0,0 +1,69 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the axis-aligned bounding box of the points in the mesh.     *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Number of points the mesh buffer currently has room for.                  */
+use crate::buffer_capacity::point_capacity;
+
+/*  Function for computing the smallest box, with sides parallel to the axes, *
+ *  that contains the mesh. The output is [x_min, y_min, z_min, x_max, y_max, *
+ *  z_max]. An empty mesh returns the degenerate box at the origin.           */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn mesh_bounding_box(ptr: *const f32, n_pts: u32) -> [f32; 6] {
+
+    /*  Avoiding going beyond the bounds of the array that was allocated.     *
+     *  The points are at most what the mesh buffer has room for.             */
+    if (n_pts as usize) > point_capacity() {
+        return [0.0; 6];
+    }
+
+    /*  Convert the pointer into a slice.                                     */
+    let n_elements: usize = 3 * (n_pts as usize);
+    let arr = unsafe { std::slice::from_raw_parts(ptr, n_elements) };
+
+    /*  There are no points to look at if the mesh is empty.                  */
+    if n_pts == 0 {
+        return [0.0; 6];
+    }
+
+    /*  Start with the box containing only the first point in the mesh.       */
+    let mut bounds: [f32; 6] = [arr[0], arr[1], arr[2], arr[0], arr[1], arr[2]];
+
+    /*  Loop through each point in the mesh and expand the box as needed.     */
+    for index in 1..n_pts {
+
+        /*  A vertex has three values, the x, y, and z coordinates. The index *
+         *  for the x value of the point is 3 times the current index.        */
+        let x_index: usize = (3 * index) as usize;
+
+        /*  Compare each of the three coordinates with the current bounds.    */
+        for axis in 0..3 {
+            let value: f32 = arr[x_index + axis];
+            bounds[axis] = bounds[axis].min(value);
+            bounds[axis + 3] = bounds[axis + 3].max(value);
+        }
+    }
+
+    return bounds;
+}
+/*  End of mesh_bounding_box.                                                 */
@@ -0,0 +1,70 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Returns a copy of the used part of the mesh buffer.                   *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  The two buffers for the mesh, and which of the two is in front.           */
+use crate::{MESH_BUFFER, MESH_BUFFER_BACK, MESH_BUFFERS_SWAPPED};
+
+/*  Used for reading the flag set by swap_mesh_buffers.                       */
+use std::sync::atomic::Ordering;
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  The current capacity of the buffers.                                      */
+use crate::buffer_capacity::buffer_capacity;
+
+/*  Number of elements in the vertex array for the given dimensions.          */
+use crate::vertex_float_count::vertex_float_count;
+
+/*  Function for copying the first nx_pts * ny_pts points of the front mesh   *
+ *  buffer, the one returned by get_mesh_buffer. wasm-bindgen hands the       *
+ *  vector to JavaScript as a Float32Array. Unlike a view made from the       *
+ *  address, the copy stays valid if the wasm memory grows, at the cost of    *
+ *  copying the data every call.                                              */
+pub fn mesh_buffer_view(nx_pts: u32, ny_pts: u32) -> Vec<f32> {
+
+    /*  The static buffer only has room for the dimensions given to           *
+     *  init_buffers. Asking for more is an error, return an empty array.     */
+    let (width, height): (u32, u32) = buffer_capacity();
+
+    if (nx_pts > width) || (ny_pts > height) {
+        return Vec::new();
+    }
+
+    /*  The number of elements in use for the given dimensions.               */
+    let len: usize = vertex_float_count(nx_pts, ny_pts) as usize;
+
+    /*  Copy from whichever buffer is currently in front.                     */
+    let buffer = if MESH_BUFFERS_SWAPPED.load(Ordering::Acquire) {
+        lock(&MESH_BUFFER_BACK)
+    } else {
+        lock(&MESH_BUFFER)
+    };
+
+    /*  The buffer is empty if nothing has been allocated yet. The unwritten  *
+     *  part of an allocated buffer is zero, so pad with zeros to match.      */
+    let mut view: Vec<f32> = buffer.iter().take(len).copied().collect();
+    view.resize(len, 0.0);
+    return view;
+}
+/*  End of mesh_buffer_view.                                                  */
@@ -0,0 +1,120 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Serializes the mesh buffer as little-endian bytes with a small header.*
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Number of f32's per point in the mesh.                                    */
+pub use crate::VERTEX_STRIDE;
+
+/*  Copy of the front mesh buffer, as used by JavaScript.                     */
+use crate::mesh_buffer_view::mesh_buffer_view;
+
+/*  The first four bytes of the format, used to recognize it when loading.    */
+pub const MESH_BYTES_MAGIC: [u8; 4] = *b"PMSH";
+
+/*  The header is the magic, followed by the width, height, and stride, each  *
+ *  as a little-endian u32.                                                   */
+pub const MESH_BYTES_HEADER_LEN: usize = 16;
+
+/*  Function for saving the first nx_pts * ny_pts points of the front mesh    *
+ *  buffer in a raw binary format, suitable for writing to disk. The layout   *
+ *  is the 16 byte header followed by the coordinates as little-endian f32's, *
+ *  in the same order as the buffer. Little-endian is used explicitly so the  *
+ *  file reads back the same on any machine, though wasm is little-endian     *
+ *  anyway. Use load_mesh_bytes to read the data back in. Returns an empty    *
+ *  array if the dimensions are larger than the buffer.                       */
+pub fn mesh_bytes(nx_pts: u32, ny_pts: u32) -> Vec<u8> {
+
+    /*  mesh_buffer_view does the bounds checks against the buffer capacity.  */
+    let floats: Vec<f32> = mesh_buffer_view(nx_pts, ny_pts);
+
+    if floats.is_empty() {
+        return Vec::new();
+    }
+
+    let mut bytes: Vec<u8> = Vec::with_capacity(
+        MESH_BYTES_HEADER_LEN + floats.len() * std::mem::size_of::<f32>()
+    );
+
+    /*  The header, identifying the format and giving the dimensions.         */
+    bytes.extend_from_slice(&MESH_BYTES_MAGIC);
+    bytes.extend_from_slice(&nx_pts.to_le_bytes());
+    bytes.extend_from_slice(&ny_pts.to_le_bytes());
+    bytes.extend_from_slice(&VERTEX_STRIDE.to_le_bytes());
+
+    /*  The coordinates, one after the other.                                 */
+    for value in floats {
+        bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    return bytes;
+}
+/*  End of mesh_bytes.                                                        */
+
+#[cfg(test)]
+mod tests {
+    use super::{mesh_bytes, MESH_BYTES_MAGIC};
+    use crate::load_mesh_bytes::load_mesh_bytes;
+    use crate::{STATUS_INVALID, STATUS_OK, TEST_LOCK, VERTEX_STRIDE};
+
+    /*  The header and coordinates for a 3 by 2 mesh.                         */
+    fn sample_bytes() -> Vec<u8> {
+        let mut bytes: Vec<u8> = MESH_BYTES_MAGIC.to_vec();
+        bytes.extend_from_slice(&3_u32.to_le_bytes());
+        bytes.extend_from_slice(&2_u32.to_le_bytes());
+        bytes.extend_from_slice(&VERTEX_STRIDE.to_le_bytes());
+
+        for n in 0..18 {
+            let value: f32 = (n as f32) * 0.25 - 2.0;
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+
+        return bytes;
+    }
+
+    /*  Loading a mesh and saving it again gives back the same bytes.         */
+    #[test]
+    fn round_trip() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let bytes: Vec<u8> = sample_bytes();
+
+        assert_eq!(load_mesh_bytes(&bytes), STATUS_OK);
+        assert_eq!(mesh_bytes(3, 2), bytes);
+    }
+
+    /*  A wrong magic, a wrong stride, or a truncated file is rejected.       */
+    #[test]
+    fn rejects_malformed_bytes() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let bytes: Vec<u8> = sample_bytes();
+
+        let mut bad_magic: Vec<u8> = bytes.clone();
+        bad_magic[0] = b'X';
+        assert_eq!(load_mesh_bytes(&bad_magic), STATUS_INVALID);
+
+        let mut bad_stride: Vec<u8> = bytes.clone();
+        bad_stride[12] = 4;
+        assert_eq!(load_mesh_bytes(&bad_stride), STATUS_INVALID);
+
+        assert_eq!(load_mesh_bytes(&bytes[..bytes.len() - 1]), STATUS_INVALID);
+        assert_eq!(load_mesh_bytes(&bytes[..8]), STATUS_INVALID);
+    }
+}
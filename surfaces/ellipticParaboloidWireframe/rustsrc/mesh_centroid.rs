@@ -0,0 +1,74 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the average of the points in the mesh.                       *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Number of points the mesh buffer currently has room for.                  */
+use crate::buffer_capacity::point_capacity;
+
+/*  Function for computing the centroid of the vertices, the point the camera *
+ *  should orbit around. The output is [x, y, z]. An empty mesh returns the   *
+ *  origin.                                                                   */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn mesh_centroid(ptr: *const f32, n_pts: u32) -> [f32; 3] {
+
+    /*  Avoiding going beyond the bounds of the array that was allocated.     *
+     *  The points are at most what the mesh buffer has room for.             */
+    if (n_pts as usize) > point_capacity() {
+        return [0.0; 3];
+    }
+
+    /*  Convert the pointer into a slice.                                     */
+    let n_elements: usize = 3 * (n_pts as usize);
+    let arr = unsafe { std::slice::from_raw_parts(ptr, n_elements) };
+
+    /*  There are no points to average if the mesh is empty, and dividing by  *
+     *  zero would give NaN. Return the origin instead.                       */
+    if n_pts == 0 {
+        return [0.0; 3];
+    }
+
+    /*  The sums are accumulated in double precision. With hundreds of        *
+     *  thousands of points, single precision loses most of the small terms.  */
+    let mut sum: [f64; 3] = [0.0; 3];
+
+    /*  Loop through each point in the mesh and add it to the sum.            */
+    for index in 0..n_pts {
+
+        /*  The index for the x value of the point is 3 times the index.      */
+        let x_index: usize = (3 * index) as usize;
+
+        for axis in 0..3 {
+            sum[axis] += arr[x_index + axis] as f64;
+        }
+    }
+
+    /*  The centroid is the sum divided by the number of points.              */
+    let rcpr_n: f64 = 1.0 / (n_pts as f64);
+
+    return [
+        (sum[0] * rcpr_n) as f32,
+        (sum[1] * rcpr_n) as f32,
+        (sum[2] * rcpr_n) as f32
+    ];
+}
+/*  End of mesh_centroid.                                                     */
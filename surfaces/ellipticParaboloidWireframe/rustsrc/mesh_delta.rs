@@ -0,0 +1,58 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the change in each point of the mesh between two frames.     *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Number of points the mesh buffer currently has room for.                  */
+use crate::buffer_capacity::point_capacity;
+
+/*  Function for writing curr - prev, coordinate by coordinate, into out. All *
+ *  three arrays hold n_pts points, 3 * n_pts f32's. Storing these deltas     *
+ *  instead of whole frames makes recorded animations much smaller, since     *
+ *  most of the values are tiny. out may be the same array as prev or curr,   *
+ *  for example to turn the previous frame into the delta in place.           */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn mesh_delta(prev_ptr: *const f32, curr_ptr: *const f32,
+                  out_ptr: *mut f32, n_pts: u32) {
+
+    /*  Avoiding going beyond the bounds of the arrays that were allocated.   *
+     *  The points are at most what the mesh buffer has room for.             */
+    if (n_pts as usize) > point_capacity() {
+        return;
+    }
+
+    /*  The number of f32's in each of the arrays.                            */
+    let n_elements: usize = 3 * (n_pts as usize);
+
+    /*  Since out may overlap one of the inputs, slices can not be used here. *
+     *  A shared slice of prev next to a mutable slice of the same memory is  *
+     *  undefined behavior, even if every element is read before it is        *
+     *  written. Read and write through the pointers one element at a time.   */
+    for index in 0..n_elements {
+        unsafe {
+            let prev: f32 = *prev_ptr.add(index);
+            let curr: f32 = *curr_ptr.add(index);
+            *out_ptr.add(index) = curr - prev;
+        }
+    }
+}
+/*  End of mesh_delta.                                                        */
@@ -0,0 +1,140 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the surface area of the triangulated mesh.                   *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Default cutoff for triangles too small to count towards the area.         */
+pub use crate::AREA_EPSILON;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Computes the area of the triangle with vertices at the given indices.     */
+fn triangle_area(arr: &[f32],
+                 index0: usize, index1: usize, index2: usize) -> f32 {
+
+    /*  Each vertex has three values, the index for the x value of the point  *
+     *  is 3 times the vertex index.                                          */
+    let p0: usize = 3 * index0;
+    let p1: usize = 3 * index1;
+    let p2: usize = 3 * index2;
+
+    /*  The two edges coming out of the first vertex.                         */
+    let ux: f32 = arr[p1] - arr[p0];
+    let uy: f32 = arr[p1 + 1] - arr[p0 + 1];
+    let uz: f32 = arr[p1 + 2] - arr[p0 + 2];
+
+    let vx: f32 = arr[p2] - arr[p0];
+    let vy: f32 = arr[p2 + 1] - arr[p0 + 1];
+    let vz: f32 = arr[p2 + 2] - arr[p0 + 2];
+
+    /*  The cross product of the two edges. Its length is the area of the     *
+     *  parallelogram spanned by the edges, twice the area of the triangle.   */
+    let cx: f32 = uy * vz - uz * vy;
+    let cy: f32 = uz * vx - ux * vz;
+    let cz: f32 = ux * vy - uy * vx;
+
+    /*  For degenerate triangles the cross product is zero, and the square    *
+     *  root of zero is zero, so no NaN's are produced.                       */
+    return 0.5 * (cx * cx + cy * cy + cz * cz).sqrt();
+}
+/*  End of triangle_area.                                                     */
+
+/*  Function for computing the surface area of the triangulated mesh.         *
+ *  Triangles with area below eps are skipped. These come from surfaces whose *
+ *  rows collapse to a point, like the poles of the sphere, and contribute    *
+ *  nothing but rounding error. A NaN area also fails the comparison, so a    *
+ *  bad triangle can not spoil the total. Because of the cutoff the area of a *
+ *  sphere is slightly under-counted near the poles. If eps is negative or    *
+ *  NaN, which is what JavaScript passes for undefined, AREA_EPSILON is used. */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn mesh_surface_area(ptr: *const f32, nx_pts: u32, ny_pts: u32,
+                         eps: f32) -> f32 {
+
+    /*  Avoiding reading beyond the bounds of the array that was allocated.   *
+     *  This is done before the slice is created.                             */
+    if capacity_status(nx_pts, ny_pts) != STATUS_OK {
+        return 0.0;
+    }
+
+    /*  The number of elements in the vertex array. Each point has 3 f32's.   */
+    let len: usize = 3 * (nx_pts as usize) * (ny_pts as usize);
+
+    /*  Convert the pointer into a slice.                                     */
+    let arr = unsafe { std::slice::from_raw_parts(ptr, len) };
+
+    /*  Variable for the total area. Summing hundreds of thousands of small   *
+     *  areas in single precision loses accuracy, so use double precision.    */
+    let mut area: f64 = 0.0;
+
+    /*  Fall back to the default cutoff for missing or invalid values.        */
+    let cutoff: f32 = if eps.is_nan() || (eps < 0.0) {
+        AREA_EPSILON
+    } else {
+        eps
+    };
+
+    /*  A grid with fewer than two points along an axis has no squares.       */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return 0.0;
+    }
+
+    /*  Loop over the bottom left corners of the squares in the grid, using   *
+     *  the same triangles and ordering as generate_triangle_indices.         */
+    for y_index in 0..(ny_pts - 1) {
+
+        /*  The indices are row-major, meaning index = y * width + x. The     *
+         *  shift factor only depends on the y-component, compute this.       */
+        let shift: u32 = y_index * nx_pts;
+
+        /*  The vertical component is now fixed, loop through the horizontal. */
+        for x_index in 0..(nx_pts - 1) {
+
+            /*  The four corners of the current square.                       */
+            let index00: usize = (shift + x_index) as usize;
+            let index01: usize = index00 + 1;
+            let index10: usize = index00 + nx_pts as usize;
+            let index11: usize = index10 + 1;
+
+            /*  The areas of the two triangles making up the square.          */
+            let lower: f32 = triangle_area(arr, index00, index01, index11);
+            let upper: f32 = triangle_area(arr, index00, index11, index10);
+
+            /*  Add the triangles that are large enough to the total.         */
+            if lower >= cutoff {
+                area += lower as f64;
+            }
+
+            if upper >= cutoff {
+                area += upper as f64;
+            }
+        }
+        /*  End of horizontal for-loop.                                       */
+    }
+    /*  End of vertical for-loop.                                             */
+
+    return area as f32;
+}
+/*  End of mesh_surface_area.                                                 */
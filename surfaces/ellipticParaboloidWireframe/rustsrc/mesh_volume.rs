@@ -0,0 +1,78 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the volume under the surface z = ax^2 + by^2 using the       *
+ *      midpoint rule.                                                        *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Physical width and height of the surface (projection onto the xy plane).  */
+pub use crate::{PARABOLOID_WIDTH, PARABOLOID_HEIGHT};
+
+/*  Left-most and bottom-most extremes of the surface (projected to xy plane).*/
+pub use crate::{PARABOLOID_X_START, PARABOLOID_Y_START};
+
+/*  Function for computing the volume between the xy plane and the surface z  *
+ *  = ax^2 + by^2 over the domain used by generate_mesh. The height is the    *
+ *  unshifted one, so the result can be compared with the exact integral.     */
+pub fn mesh_volume(nx_pts: u32, ny_pts: u32, a: f32, b: f32) -> f32 {
+
+    /*  A grid with fewer than two points along an axis has no squares.       */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return 0.0;
+    }
+
+    /*  Step sizes in the horizontal and vertical axes. As in                 *
+     *  mesh_surface_area, summing many small values in single precision      *
+     *  loses accuracy, so use double precision.                              */
+    let dx: f64 = (PARABOLOID_WIDTH as f64) / ((nx_pts - 1) as f64);
+    let dy: f64 = (PARABOLOID_HEIGHT as f64) / ((ny_pts - 1) as f64);
+
+    /*  The bottom left corner of the domain.                                 */
+    let x_start: f64 = PARABOLOID_X_START as f64;
+    let y_start: f64 = PARABOLOID_Y_START as f64;
+
+    /*  Variable for the sum of the heights at the centers of the squares.    */
+    let mut sum: f64 = 0.0;
+
+    /*  Loop over the squares in the grid. The midpoint rule samples the      *
+     *  height at the center of each square.                                  */
+    for y_index in 0..(ny_pts - 1) {
+
+        /*  The y coordinate of the centers of the squares in this row.       */
+        let y_pt: f64 = y_start + ((y_index as f64) + 0.5) * dy;
+        let by2: f64 = (b as f64) * y_pt * y_pt;
+
+        for x_index in 0..(nx_pts - 1) {
+
+            /*  The x coordinate of the center of the current square.         */
+            let x_pt: f64 = x_start + ((x_index as f64) + 0.5) * dx;
+
+            sum += (a as f64) * x_pt * x_pt + by2;
+        }
+        /*  End of horizontal for-loop.                                       */
+    }
+    /*  End of vertical for-loop.                                             */
+
+    /*  Every square has the same area, so multiply once at the end. For a    *
+     *  quadratic, the error of the midpoint rule is proportional to the      *
+     *  square of the step size.                                              */
+    return (sum * dx * dy) as f32;
+}
+/*  End of mesh_volume.                                                       */
@@ -0,0 +1,63 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the smallest and largest heights of the points in the mesh.  *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Number of points the mesh buffer currently has room for.                  */
+use crate::buffer_capacity::point_capacity;
+
+/*  Function for scanning the mesh for the range of its z coordinates, for    *
+ *  scaling a colormap. Since this looks at the buffer itself, it works for   *
+ *  any surface, after any rotation, without needing its formula. The output  *
+ *  is [z_min, z_max]. An empty mesh returns [0, 0].                          */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn mesh_z_range(ptr: *const f32, n_pts: u32) -> [f32; 2] {
+
+    /*  Avoiding going beyond the bounds of the array that was allocated.     *
+     *  The points are at most what the mesh buffer has room for.             */
+    if (n_pts as usize) > point_capacity() {
+        return [0.0; 2];
+    }
+
+    /*  Convert the pointer into a slice.                                     */
+    let n_elements: usize = 3 * (n_pts as usize);
+    let arr = unsafe { std::slice::from_raw_parts(ptr, n_elements) };
+
+    /*  There are no points to look at if the mesh is empty.                  */
+    if n_pts == 0 {
+        return [0.0; 2];
+    }
+
+    /*  Start with the range containing only the first point in the mesh.     */
+    let mut range: [f32; 2] = [arr[2], arr[2]];
+
+    /*  Loop through the rest of the points and widen the range as needed.    *
+     *  The z coordinate of the point is 2 after its x coordinate.            */
+    for index in 1..n_pts {
+        let z: f32 = arr[(3 * index + 2) as usize];
+        range[0] = range[0].min(z);
+        range[1] = range[1].max(z);
+    }
+
+    return range;
+}
+/*  End of mesh_z_range.                                                      */
@@ -0,0 +1,135 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the points in the mesh for the isometric deformation of the  *
+ *      catenoid into the helicoid.                                           *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Range of the height parameter for the catenoid.                           */
+pub use crate::{CATENOID_U_START, CATENOID_U_WIDTH};
+
+/*  The angular parameter runs over [0, 2 pi].                                */
+use std::f32::consts::{FRAC_PI_2, PI};
+
+/*  Computes the length of the buffer, checking for overflow.                 */
+use crate::checked_len::checked_len;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Functions for converting the points for y up, see set_up_axis.            */
+use crate::up_axis::{is_y_up, convert_up_axis};
+
+/*  Function for morphing between the catenoid (t = 0) and helicoid (t = 1).  */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn morph_catenoid_helicoid(ptr: *mut f32, n_u: u32, n_v: u32,
+                               c: f32, t: f32) {
+
+    /*  The height is divided by c, so it must be non-zero and finite.        *
+     *  Otherwise every point would be NaN.                                   */
+    if !c.is_finite() || (c == 0.0) {
+        return;
+    }
+
+    /*  A grid needs at least two points along each axis. Otherwise the step  *
+     *  size divides by zero and (n - 1) underflows, so return early.         */
+    if (n_u < 2) || (n_v < 2) {
+        return;
+    }
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(n_u, n_v) != STATUS_OK {
+        return;
+    }
+
+    /*  The size of the array passed to us is given by the number of points   *
+     *  in the mesh, which is simply n_u times n_v. Each point is 3 f32's.    *
+     *  The product is checked for overflow.                                  */
+    let len: usize = match checked_len(n_u, n_v, 3) {
+        Some(len) => len,
+        None => return,
+    };
+
+    /*  Get a slice for the data.                                             */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Step sizes for the height and angular parameters.                     */
+    let du: f32 = CATENOID_U_WIDTH / ((n_u - 1) as f32);
+    let dv: f32 = 2.0 * PI / ((n_v - 1) as f32);
+
+    /*  The deformation is parametrized by an angle a in [0, pi / 2], with a  *
+     *  = pi / 2 giving the catenoid and a = 0 giving the helicoid. Clamp t   *
+     *  to [0, 1] and convert it to this angle.                               */
+    let angle: f32 = (1.0 - t.clamp(0.0, 1.0)) * FRAC_PI_2;
+    let (sin_a, cos_a): (f32, f32) = angle.sin_cos();
+
+    /*  Whether to convert the points for y up, see set_up_axis.              */
+    let y_up: bool = is_y_up();
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  Loop through the points in the same order as generate_catenoid, so    *
+     *  that t = 0 reproduces it up to rounding and generate_indices(ptr,     *
+     *  n_u, n_v) gives the wireframe for every t.                            */
+    for v_index in 0..n_v {
+
+        /*  Convert the index to an angle.                                    */
+        let v: f32 = (v_index as f32) * dv;
+        let cos_v: f32 = v.cos();
+        let sin_v: f32 = v.sin();
+
+        /*  Loop through the height parameter.                                */
+        for u_index in 0..n_u {
+
+            /*  Convert the index to a height, and rescale by c.              */
+            let u: f32 = CATENOID_U_START + (u_index as f32) * du;
+            let s: f32 = u / c;
+            let sinh_s: f32 = c * s.sinh();
+            let cosh_s: f32 = c * s.cosh();
+
+            /*  The classic deformation through minimal surfaces. Every       *
+             *  surface in the family is locally isometric to the others, and *
+             *  is given by                                                   *
+             *                                                                *
+             *      x =  cos(a) c sinh(s) sin(v) + sin(a) c cosh(s) cos(v)    *
+             *      y = -cos(a) c sinh(s) cos(v) + sin(a) c cosh(s) sin(v)    *
+             *      z =  cos(a) c v + sin(a) u                                *
+             *                                                                *
+             *  where s = u / c. At t = 1 this is the helicoid with radial    *
+             *  parameter c sinh(s), rotated by a quarter turn.               */
+            arr[index] = cos_a * sinh_s * sin_v + sin_a * cosh_s * cos_v;
+            arr[index + 1] = sin_a * cosh_s * sin_v - cos_a * sinh_s * cos_v;
+            arr[index + 2] = cos_a * c * v + sin_a * u;
+            convert_up_axis(arr, index, y_up);
+
+            /*  Move on to the next point in the mesh. A point needs 3 f32's. */
+            index += 3;
+        }
+        /*  End of u for-loop.                                                */
+    }
+    /*  End of v for-loop.                                                    */
+}
+/*  End of morph_catenoid_helicoid.                                           */
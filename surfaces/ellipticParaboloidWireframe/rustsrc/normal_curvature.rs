@@ -0,0 +1,61 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the normal curvature of the paraboloid at a point in a given *
+ *      direction.                                                            *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Function for computing the normal curvature of z = a x^2 + b y^2 at the   *
+ *  point above (x, y), in the tangent direction above (dir_x, dir_y). This   *
+ *  is the curvature of the slice of the surface by the plane containing the  *
+ *  normal and that direction. It is the ratio of the second and first        *
+ *  fundamental forms, II(T) / I(T), for the tangent vector                   *
+ *                                                                            *
+ *          T = (dir_x, dir_y, f_x dir_x + f_y dir_y).                        *
+ *                                                                            *
+ *  The sign is positive where the surface bends towards the upward normal.   *
+ *  The direction does not need to be normalized. The zero vector is not a    *
+ *  direction, so zero is returned for it.                                    */
+pub fn normal_curvature(x: f32, y: f32, dir_x: f32, dir_y: f32,
+                        a: f32, b: f32) -> f32 {
+
+    /*  The first derivatives of f(x, y) = a x^2 + b y^2.                     */
+    let fx: f32 = 2.0 * a * x;
+    let fy: f32 = 2.0 * b * y;
+
+    /*  The z component of the tangent vector, by the chain rule.             */
+    let dir_z: f32 = fx * dir_x + fy * dir_y;
+
+    /*  The first fundamental form is the squared length of the tangent.      */
+    let first_form: f32 = dir_x * dir_x + dir_y * dir_y + dir_z * dir_z;
+
+    if first_form == 0.0 {
+        return 0.0;
+    }
+
+    /*  The second derivatives are f_xx = 2a, f_yy = 2b, and f_xy = 0. The    *
+     *  second fundamental form is the Hessian applied to the direction,      *
+     *  divided by the length of the normal (-f_x, -f_y, 1).                  */
+    let hessian: f32 = 2.0 * a * dir_x * dir_x + 2.0 * b * dir_y * dir_y;
+    let second_form: f32 = hessian / (1.0 + fx * fx + fy * fy).sqrt();
+
+    return second_form / first_form;
+}
+/*  End of normal_curvature.                                                  */
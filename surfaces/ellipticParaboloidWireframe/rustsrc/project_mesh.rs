@@ -0,0 +1,106 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Applies a perspective projection to the mesh.                         *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Number of points the mesh buffer currently has room for.                  */
+use crate::buffer_capacity::point_capacity;
+
+/*  Function for projecting the mesh onto the screen. The camera sits at the  *
+ *  origin looking down the negative z axis, with the y axis up, as in        *
+ *  three.js. fov is the vertical field of view in radians, aspect is the     *
+ *  width of the screen divided by the height, and near and far are the       *
+ *  distances to the clipping planes. The surfaces are centered on the        *
+ *  origin, so they should first be moved in front of the camera, for example *
+ *  with translate_mesh(ptr, n_pts, 0.0, 0.0, -5.0). Each point is replaced   *
+ *  by its normalized device coordinates, with x and y in [-1, 1] for points  *
+ *  on the screen and z in [-1, 1] between the clipping planes.               */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn project_mesh(ptr: *mut f32, n_pts: u32,
+                    fov: f32, aspect: f32, near: f32, far: f32) {
+
+    /*  Avoiding going beyond the bounds of the array that was allocated.     *
+     *  The points are at most what the mesh buffer has room for.             */
+    if (n_pts as usize) > point_capacity() {
+        return;
+    }
+
+    /*  Convert the pointer into a slice.                                     */
+    let n_elements: usize = 3 * (n_pts as usize);
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, n_elements) };
+
+    /*  The entries of the perspective matrix, the same matrix as the one     *
+     *  used by three.js and OpenGL:                                          *
+     *                                                                        *
+     *        [ f / aspect   0          0                    0          ]     *
+     *        [ 0            f          0                    0          ]     *
+     *        [ 0            0   (f + n) / (n - f)   2 f n / (n - f)     ]    *
+     *        [ 0            0         -1                    0          ]     *
+     *                                                                        *
+     *  where f = 1 / tan(fov / 2) in the first two rows, and f and n are the *
+     *  far and near distances in the third. The remaining entries are zero,  *
+     *  so only four numbers are needed.                                      */
+    let focal: f32 = 1.0 / (0.5 * fov).tan();
+    let rcpr_depth: f32 = 1.0 / (near - far);
+
+    let m00: f32 = focal / aspect;
+    let m11: f32 = focal;
+    let m22: f32 = (far + near) * rcpr_depth;
+    let m23: f32 = 2.0 * far * near * rcpr_depth;
+
+    /*  Loop through each point in the mesh.                                  */
+    for index in 0..n_pts {
+
+        /*  A vertex has three values, the x, y, and z coordinates. The index *
+         *  for the x value of the point is 3 times the current index.        */
+        let x_index: usize = (3 * index) as usize;
+
+        /*  The y and z indices are immediately after the x index.            */
+        let y_index: usize = x_index + 1;
+        let z_index: usize = x_index + 2;
+
+        /*  Get the initial values.                                           */
+        let x: f32 = arr[x_index];
+        let y: f32 = arr[y_index];
+        let z: f32 = arr[z_index];
+
+        /*  The fourth coordinate of the projected point is the distance in   *
+         *  front of the camera. Points on or behind the camera have no       *
+         *  position on the screen, mark them with NaN so they can be         *
+         *  skipped.                                                          */
+        let w: f32 = -z;
+
+        if w <= 0.0 {
+            arr[x_index] = f32::NAN;
+            arr[y_index] = f32::NAN;
+            arr[z_index] = f32::NAN;
+            continue;
+        }
+
+        /*  Apply the matrix and divide by w to get the device coordinates.   */
+        let rcpr_w: f32 = 1.0 / w;
+        arr[x_index] = m00 * x * rcpr_w;
+        arr[y_index] = m11 * y * rcpr_w;
+        arr[z_index] = (m22 * z + m23) * rcpr_w;
+    }
+}
+/*  End of project_mesh.                                                      */
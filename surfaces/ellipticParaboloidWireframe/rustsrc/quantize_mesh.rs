@@ -0,0 +1,59 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Rounds the points in the mesh to a regular lattice.                   *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Number of points the mesh buffer currently has room for.                  */
+use crate::buffer_capacity::point_capacity;
+
+/*  Function for snapping every coordinate of every point in the mesh to the  *
+ *  nearest multiple of step. This gives the surface a faceted, low-poly      *
+ *  look.                                                                     */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn quantize_mesh(ptr: *mut f32, n_pts: u32, step: f32) {
+
+    /*  A lattice needs a positive, finite spacing. An infinite step would    *
+     *  turn every point into NaN, since 0 times infinity is undefined.       */
+    if !step.is_finite() || (step <= 0.0) {
+        return;
+    }
+
+    /*  Avoiding going beyond the bounds of the array that was allocated.     *
+     *  The points are at most what the mesh buffer has room for.             */
+    if (n_pts as usize) > point_capacity() {
+        return;
+    }
+
+    /*  Convert the pointer into a slice.                                     */
+    let n_elements: usize = 3 * (n_pts as usize);
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, n_elements) };
+
+    /*  Multiplying is cheaper than dividing, compute the reciprocal once.    */
+    let rcpr_step: f32 = 1.0 / step;
+
+    /*  The x, y, and z coordinates are all treated the same, so there is no  *
+     *  need to loop over the points one at a time.                           */
+    for value in arr.iter_mut() {
+        *value = (*value * rcpr_step).round() * step;
+    }
+}
+/*  End of quantize_mesh.                                                     */
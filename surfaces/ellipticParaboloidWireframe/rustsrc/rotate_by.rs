@@ -0,0 +1,53 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Increments the rotation angle and updates its sine and cosine.        *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Globals for the program, the rotation angle and its sine and cosine.      */
+use crate::{ROTATION_ANGLE, COS_ANGLE, SIN_ANGLE};
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  Range reduction and the sine and cosine used by set_rotation_angle.       */
+use crate::set_rotation_angle::{cos_and_sin, reduce_angle};
+
+/*  Function for adding to the rotation angle.                                */
+pub fn rotate_by(delta: f32) {
+
+    /*  Get variables for the globals.                                        */
+    let mut rotation = lock(&ROTATION_ANGLE);
+    let mut cos_val = lock(&COS_ANGLE);
+    let mut sin_val = lock(&SIN_ANGLE);
+
+    /*  Increment the angle and wrap it back into [-pi, pi]. This keeps the   *
+     *  angle small, so no precision is lost after thousands of frames.       */
+    let angle: f32 = reduce_angle(*rotation + delta);
+
+    /*  Compute the sine and cosine with the range-reduced Taylor series.     */
+    let (cos_angle, sin_angle): (f32, f32) = cos_and_sin(angle);
+
+    /*  Set the globals to their new values.                                  */
+    *rotation = angle;
+    *cos_val = cos_angle;
+    *sin_val = sin_angle;
+}
+/*  End of rotate_by.                                                         */
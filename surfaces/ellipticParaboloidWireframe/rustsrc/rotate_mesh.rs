@@ -24,19 +24,77 @@
 /*  Pre-computed cosine and sine of the rotation angle.                       */
 use crate::{COS_ANGLE, SIN_ANGLE};
 
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  Converting the points to z up and back, see set_up_axis.                  */
+use crate::up_axis::{is_y_up, from_up_axis, to_up_axis};
+
+/*  Vectorized rotation, four vertices at a time, for wasm with SIMD.         */
+#[cfg(all(feature = "wasm-simd",
+          target_arch = "wasm32", target_feature = "simd128"))]
+use crate::rotate_mesh_simd::rotate_mesh_simd;
+
+/*  Number of points the mesh buffer currently has room for.                  */
+use crate::buffer_capacity::point_capacity;
+
 /*  Function for rotating the mesh by a fixed angle.                          */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
 pub fn rotate_mesh(ptr: *mut f32, n_pts: u32) {
 
+    /*  Avoiding going beyond the bounds of the array that was allocated.     *
+     *  The points are at most what the mesh buffer has room for.             */
+    if (n_pts as usize) > point_capacity() {
+        return;
+    }
+
     /*  Convert the pointer into a slice.                                     */
-    let n_elements = (3 * n_pts) as usize;
+    let n_elements: usize = 3 * (n_pts as usize);
     let arr = unsafe { std::slice::from_raw_parts_mut(ptr, n_elements) };
 
     /*  Get the cosine and sine of the angle as f32's.                        */
-    let cos_angle: f32 = *COS_ANGLE.lock().unwrap();
-    let sin_angle: f32 = *SIN_ANGLE.lock().unwrap();
+    let cos_angle: f32 = *lock(&COS_ANGLE);
+    let sin_angle: f32 = *lock(&SIN_ANGLE);
+
+    /*  An infinite or NaN angle given to set_rotation_angle makes both of    *
+     *  these NaN. Rotating would then replace every vertex with NaN and the  *
+     *  surface would vanish. Leave the mesh as it is instead.                */
+    if !cos_angle.is_finite() || !sin_angle.is_finite() {
+        return;
+    }
+
+    /*  The surface spins about its vertical axis. With y up the points are   *
+     *  converted to z up, rotated about the z axis, and converted back.      */
+    if is_y_up() {
+        for point in arr.chunks_exact_mut(3) {
+            let p: [f32; 3] = [point[0], point[1], point[2]];
+            let [x, y, z]: [f32; 3] = from_up_axis(p, true);
+            let rotated: [f32; 3] = [
+                cos_angle * x - sin_angle * y,
+                cos_angle * y + sin_angle * x,
+                z
+            ];
+
+            point.copy_from_slice(&to_up_axis(rotated, true));
+        }
 
-    /*  Loop through each point in the mesh.                                  */
-    for index in 0..n_pts {
+        return;
+    }
+
+    /*  With the wasm-simd feature the bulk of the mesh is rotated four       *
+     *  vertices at a time. The scalar loop below handles the leftovers.      */
+    #[cfg(all(feature = "wasm-simd",
+              target_arch = "wasm32", target_feature = "simd128"))]
+    let start: u32 = rotate_mesh_simd(arr, cos_angle, sin_angle);
+
+    /*  Without SIMD the scalar loop rotates every vertex.                    */
+    #[cfg(not(all(feature = "wasm-simd",
+                  target_arch = "wasm32", target_feature = "simd128")))]
+    let start: u32 = 0;
+
+    /*  Loop through each point in the mesh that has not been rotated yet.    */
+    for index in start..n_pts {
 
         /*  A vertex has three values, the x, y, and z coordinates. The index *
          *  for the x value of the point is 3 times the current index.        */
@@ -55,3 +113,27 @@ pub fn rotate_mesh(ptr: *mut f32, n_pts: u32) {
     }
 }
 /*  End of rotate_mesh.                                                       */
+
+#[cfg(test)]
+mod tests {
+    use super::rotate_mesh;
+    use crate::set_rotation_angle::set_rotation_angle;
+    use crate::TEST_LOCK;
+
+    /*  An infinite angle makes the cached sine and cosine NaN. The mesh is   *
+     *  left as it was rather than being filled with NaN.                     */
+    #[test]
+    fn infinite_angle_leaves_mesh_intact() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let original: [f32; 12] = [
+            1.0, 2.0, 3.0, -1.0, 0.5, 2.0, 0.0, -4.0, 1.0, 7.0, 7.0, -7.0
+        ];
+
+        let mut arr: [f32; 12] = original;
+        set_rotation_angle(f32::INFINITY);
+        rotate_mesh(arr.as_mut_ptr(), 4);
+        set_rotation_angle(0.0);
+
+        assert_eq!(arr, original);
+    }
+}
@@ -0,0 +1,118 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Rotates the mesh by a fixed angle about an arbitrary axis.            *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Pre-computed cosine and sine of the rotation angle.                       */
+use crate::{COS_ANGLE, SIN_ANGLE};
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  Number of points the mesh buffer currently has room for.                  */
+use crate::buffer_capacity::point_capacity;
+
+/*  Converting the points to z up and back, see set_up_axis.                  */
+use crate::up_axis::{is_y_up, from_up_axis, to_up_axis};
+
+/*  Function for rotating the mesh about the axis (ax, ay, az). The axis is   *
+ *  given with z up, even when y is up, as in rotate_mesh_euler.              */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn rotate_mesh_axis(ptr: *mut f32, n_pts: u32, ax: f32, ay: f32, az: f32) {
+
+    /*  The axis does not need to be a unit vector, normalize it first.       */
+    let norm: f32 = (ax * ax + ay * ay + az * az).sqrt();
+
+    /*  The zero vector does not define an axis, there is nothing to do.      */
+    if norm == 0.0 {
+        return;
+    }
+
+    /*  Avoiding going beyond the bounds of the array that was allocated.     *
+     *  The points are at most what the mesh buffer has room for.             */
+    if (n_pts as usize) > point_capacity() {
+        return;
+    }
+
+    /*  Convert the pointer into a slice.                                     */
+    let n_elements: usize = 3 * (n_pts as usize);
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, n_elements) };
+
+    /*  Components of the unit vector for the axis.                           */
+    let rcpr_norm: f32 = 1.0 / norm;
+    let kx: f32 = ax * rcpr_norm;
+    let ky: f32 = ay * rcpr_norm;
+    let kz: f32 = az * rcpr_norm;
+
+    /*  Get the cosine and sine of the angle as f32's.                        */
+    let cos_angle: f32 = *lock(&COS_ANGLE);
+    let sin_angle: f32 = *lock(&SIN_ANGLE);
+    let one_minus_cos: f32 = 1.0 - cos_angle;
+
+    /*  Rodrigues' formula gives the rotation about the unit vector k:        *
+     *                                                                        *
+     *        R = cos(t) I + sin(t) K + (1 - cos(t)) k k^T                    *
+     *                                                                        *
+     *  where K is the cross product matrix of k, Kv = k x v. For the axis    *
+     *  (0, 0, 1) this agrees with rotate_mesh. Compute the nine entries of   *
+     *  the matrix once, before the loop.                                     */
+    let m00: f32 = cos_angle + one_minus_cos * kx * kx;
+    let m01: f32 = one_minus_cos * kx * ky - sin_angle * kz;
+    let m02: f32 = one_minus_cos * kx * kz + sin_angle * ky;
+
+    let m10: f32 = one_minus_cos * ky * kx + sin_angle * kz;
+    let m11: f32 = cos_angle + one_minus_cos * ky * ky;
+    let m12: f32 = one_minus_cos * ky * kz - sin_angle * kx;
+
+    let m20: f32 = one_minus_cos * kz * kx - sin_angle * ky;
+    let m21: f32 = one_minus_cos * kz * ky + sin_angle * kx;
+    let m22: f32 = cos_angle + one_minus_cos * kz * kz;
+
+    /*  Whether the points are stored with y up, see set_up_axis.             */
+    let y_up: bool = is_y_up();
+
+    /*  Loop through each point in the mesh.                                  */
+    for index in 0..n_pts {
+
+        /*  A vertex has three values, the x, y, and z coordinates. The index *
+         *  for the x value of the point is 3 times the current index.        */
+        let x_index: usize = (3 * index) as usize;
+
+        /*  The y and z indices are immediately after the x index.            */
+        let y_index: usize = x_index + 1;
+        let z_index: usize = x_index + 2;
+
+        /*  Use the rotation matrix. Get the initial values, with z up.       */
+        let point: [f32; 3] = [arr[x_index], arr[y_index], arr[z_index]];
+        let [x, y, z]: [f32; 3] = from_up_axis(point, y_up);
+
+        /*  Apply the rotation matrix and convert back to the chosen axis.    */
+        let rotated: [f32; 3] = [
+            m00 * x + m01 * y + m02 * z,
+            m10 * x + m11 * y + m12 * z,
+            m20 * x + m21 * y + m22 * z
+        ];
+
+        arr[x_index..x_index + 3].copy_from_slice(&to_up_axis(rotated, y_up));
+    }
+}
+/*  End of rotate_mesh_axis.                                                  */
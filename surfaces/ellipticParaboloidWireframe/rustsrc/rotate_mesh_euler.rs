@@ -0,0 +1,99 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Rotates the mesh by the rotation given by three Euler angles.         *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Number of points the mesh buffer currently has room for.                  */
+use crate::buffer_capacity::point_capacity;
+
+/*  Converting the points to z up and back, see set_up_axis.                  */
+use crate::up_axis::{is_y_up, from_up_axis, to_up_axis};
+
+/*  Function for rotating the mesh by yaw, pitch, and roll angles. The axes   *
+ *  are those of the surface with z up, even when y is up.                    */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn rotate_mesh_euler(ptr: *mut f32, n_pts: u32,
+                         yaw: f32, pitch: f32, roll: f32) {
+
+    /*  Avoiding going beyond the bounds of the array that was allocated.     *
+     *  The points are at most what the mesh buffer has room for.             */
+    if (n_pts as usize) > point_capacity() {
+        return;
+    }
+
+    /*  Convert the pointer into a slice.                                     */
+    let n_elements: usize = 3 * (n_pts as usize);
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, n_elements) };
+
+    /*  Compute the sines and cosines of the three angles. These may be       *
+     *  arbitrary angles, not just small ones, so we use the full-range sine  *
+     *  and cosine functions instead of the Taylor approximations.            */
+    let (sin_yaw, cos_yaw): (f32, f32) = yaw.sin_cos();
+    let (sin_pitch, cos_pitch): (f32, f32) = pitch.sin_cos();
+    let (sin_roll, cos_roll): (f32, f32) = roll.sin_cos();
+
+    /*  The rotation is R = Rz(yaw) Ry(pitch) Rx(roll). That is, we first     *
+     *  rotate about the x axis by the roll angle, then the y axis by the     *
+     *  pitch angle, and lastly the z axis by the yaw angle. Each of these    *
+     *  agrees with rotate_mesh_x, rotate_mesh_y, and rotate_mesh. Compute    *
+     *  the nine entries of the composed matrix once, before the loop.        */
+    let m00: f32 = cos_yaw * cos_pitch;
+    let m01: f32 = cos_yaw * sin_pitch * sin_roll - sin_yaw * cos_roll;
+    let m02: f32 = cos_yaw * sin_pitch * cos_roll + sin_yaw * sin_roll;
+
+    let m10: f32 = sin_yaw * cos_pitch;
+    let m11: f32 = sin_yaw * sin_pitch * sin_roll + cos_yaw * cos_roll;
+    let m12: f32 = sin_yaw * sin_pitch * cos_roll - cos_yaw * sin_roll;
+
+    let m20: f32 = -sin_pitch;
+    let m21: f32 = cos_pitch * sin_roll;
+    let m22: f32 = cos_pitch * cos_roll;
+
+    /*  Whether the points are stored with y up, see set_up_axis.             */
+    let y_up: bool = is_y_up();
+
+    /*  Loop through each point in the mesh.                                  */
+    for index in 0..n_pts {
+
+        /*  A vertex has three values, the x, y, and z coordinates. The index *
+         *  for the x value of the point is 3 times the current index.        */
+        let x_index: usize = (3 * index) as usize;
+
+        /*  The y and z indices are immediately after the x index.            */
+        let y_index: usize = x_index + 1;
+        let z_index: usize = x_index + 2;
+
+        /*  Use the rotation matrix. Get the initial values, with z up.       */
+        let point: [f32; 3] = [arr[x_index], arr[y_index], arr[z_index]];
+        let [x, y, z]: [f32; 3] = from_up_axis(point, y_up);
+
+        /*  Apply the rotation matrix and convert back to the chosen axis.    */
+        let rotated: [f32; 3] = [
+            m00 * x + m01 * y + m02 * z,
+            m10 * x + m11 * y + m12 * z,
+            m20 * x + m21 * y + m22 * z
+        ];
+
+        arr[x_index..x_index + 3].copy_from_slice(&to_up_axis(rotated, y_up));
+    }
+}
+/*  End of rotate_mesh_euler.                                                 */
@@ -0,0 +1,84 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Rotates the mesh by a fixed angle about the x axis.                   *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Pre-computed cosine and sine of the rotation angle.                       */
+use crate::{COS_ANGLE, SIN_ANGLE};
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  Number of points the mesh buffer currently has room for.                  */
+use crate::buffer_capacity::point_capacity;
+
+/*  Converting the points to z up and back, see set_up_axis.                  */
+use crate::up_axis::{is_y_up, from_up_axis, to_up_axis};
+
+/*  Function for rotating the mesh about the x axis by a fixed angle. This is *
+ *  the x axis of the surface with z up, the same axis when y is up.          */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn rotate_mesh_x(ptr: *mut f32, n_pts: u32) {
+
+    /*  Avoiding going beyond the bounds of the array that was allocated.     *
+     *  The points are at most what the mesh buffer has room for.             */
+    if (n_pts as usize) > point_capacity() {
+        return;
+    }
+
+    /*  Convert the pointer into a slice.                                     */
+    let n_elements: usize = 3 * (n_pts as usize);
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, n_elements) };
+
+    /*  Get the cosine and sine of the angle as f32's.                        */
+    let cos_angle: f32 = *lock(&COS_ANGLE);
+    let sin_angle: f32 = *lock(&SIN_ANGLE);
+
+    /*  Whether the points are stored with y up, see set_up_axis.             */
+    let y_up: bool = is_y_up();
+
+    /*  Loop through each point in the mesh.                                  */
+    for index in 0..n_pts {
+
+        /*  A vertex has three values, the x, y, and z coordinates. The index *
+         *  for the x value of the point is 3 times the current index.        */
+        let x_index: usize = (3 * index) as usize;
+
+        /*  The y and z indices are immediately after the x index.            */
+        let y_index: usize = x_index + 1;
+        let z_index: usize = x_index + 2;
+
+        /*  Use the rotation matrix. Get the initial values, with z up.       */
+        let point: [f32; 3] = [arr[x_index], arr[y_index], arr[z_index]];
+        let [x, y, z]: [f32; 3] = from_up_axis(point, y_up);
+
+        /*  Apply the rotation matrix and convert back to the chosen axis.    */
+        let rotated: [f32; 3] = [
+            x,
+            cos_angle * y - sin_angle * z,
+            cos_angle * z + sin_angle * y
+        ];
+
+        arr[x_index..x_index + 3].copy_from_slice(&to_up_axis(rotated, y_up));
+    }
+}
+/*  End of rotate_mesh_x.                                                     */
@@ -0,0 +1,72 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Rotates the orientation quaternion by an angle about an axis.         *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Orientation of the mesh, stored as the unit quaternion (w, x, y, z).      */
+use crate::ORIENTATION;
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  Function for composing the orientation with a rotation about an axis.     */
+pub fn rotate_orientation(ax: f32, ay: f32, az: f32, delta: f32) {
+
+    /*  The axis does not need to be a unit vector, normalize it first.       */
+    let norm: f32 = (ax * ax + ay * ay + az * az).sqrt();
+
+    /*  The zero vector does not define an axis, there is nothing to do.      */
+    if norm == 0.0 {
+        return;
+    }
+
+    /*  The rotation by delta about the unit vector k is given by the         *
+     *  quaternion (cos(delta / 2), sin(delta / 2) k). The angle may be       *
+     *  arbitrary, so use the full-range sine and cosine functions.           */
+    let (sin_half, cos_half): (f32, f32) = (0.5 * delta).sin_cos();
+    let scale: f32 = sin_half / norm;
+
+    let dw: f32 = cos_half;
+    let dx: f32 = ax * scale;
+    let dy: f32 = ay * scale;
+    let dz: f32 = az * scale;
+
+    /*  Get the current orientation.                                          */
+    let mut orientation = lock(&ORIENTATION);
+    let [qw, qx, qy, qz]: [f32; 4] = *orientation;
+
+    /*  The new orientation is d * q, so the increment is applied after the   *
+     *  current orientation, about the fixed axes of the scene.               */
+    let w: f32 = dw * qw - dx * qx - dy * qy - dz * qz;
+    let x: f32 = dw * qx + dx * qw + dy * qz - dz * qy;
+    let y: f32 = dw * qy - dx * qz + dy * qw + dz * qx;
+    let z: f32 = dw * qz + dx * qy - dy * qx + dz * qw;
+
+    /*  The product of unit quaternions is a unit quaternion, but rounding    *
+     *  error builds up after many frames and the mesh starts to shear.       *
+     *  Renormalizing is four multiplies and a square root, so do it on every *
+     *  call rather than keeping track of how many steps have passed.         */
+    let rcpr_norm: f32 = 1.0 / (w * w + x * x + y * y + z * z).sqrt();
+
+    /*  Store the result.                                                     */
+    *orientation = [w * rcpr_norm, x * rcpr_norm, y * rcpr_norm, z * rcpr_norm];
+}
+/*  End of rotate_orientation.                                                */
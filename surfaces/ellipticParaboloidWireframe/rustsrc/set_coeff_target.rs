@@ -0,0 +1,56 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Starts easing the coefficients of the paraboloid towards new values.  *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  State for the transition of the coefficients.                             */
+use crate::COEFF_EASING;
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  Function for starting a transition of the coefficients (a, b) of the      *
+ *  surface z = a x^2 + b y^2 towards new values. The transition starts from  *
+ *  the current coefficients, so a new target may be set part way through     *
+ *  another transition without the surface jumping. Call tick_coeffs once per *
+ *  frame to advance it. A duration that is zero, negative, or not finite     *
+ *  moves the coefficients to the target at once.                             */
+pub fn set_coeff_target(a: f32, b: f32, duration: f32) {
+
+    /*  Targets that are not numbers would spoil the coefficients for good.   */
+    if !a.is_finite() || !b.is_finite() {
+        return;
+    }
+
+    let mut easing = lock(&COEFF_EASING);
+
+    easing.start = easing.current;
+    easing.target = [a, b];
+    easing.elapsed = 0.0;
+    easing.duration = if duration.is_finite() && (duration > 0.0) {
+        duration
+    } else {
+        0.0
+    };
+
+    easing.update();
+}
+/*  End of set_coeff_target.                                                  */
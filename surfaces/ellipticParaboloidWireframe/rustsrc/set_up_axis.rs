@@ -0,0 +1,50 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Sets which axis points up in the generated meshes.                    *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  The axis pointing up, and the possible values.                            */
+use crate::{UP_AXIS, UP_AXIS_Y, UP_AXIS_Z};
+
+/*  Status codes returned to JavaScript.                                      */
+use crate::{STATUS_INVALID, STATUS_OK};
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  Function for choosing the up axis, UP_AXIS_Z (2) or UP_AXIS_Y (1). The    *
+ *  surfaces are graphs z = f(x, y), but three.js treats y as up, so without  *
+ *  this the scene has to be rotated every frame. With y up, every generator  *
+ *  writes (x, z, -y) in place of (x, y, z), for the normals as well as the   *
+ *  points, and update_z writes the height to the y coordinate. The rotations *
+ *  convert to z up, rotate, and convert back, so rotate_mesh still spins the *
+ *  surface about its vertical axis, and validate_winding gives the same      *
+ *  answer for either axis. The default is z up. Returns STATUS_OK, or        *
+ *  STATUS_INVALID if the axis is not one of the two values.                  */
+pub fn set_up_axis(axis: u32) -> i32 {
+    if (axis != UP_AXIS_Y) && (axis != UP_AXIS_Z) {
+        return STATUS_INVALID;
+    }
+
+    *lock(&UP_AXIS) = axis;
+    return STATUS_OK;
+}
+/*  End of set_up_axis.                                                       */
@@ -0,0 +1,72 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Applies a shear transformation to the mesh.                           *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Number of points the mesh buffer currently has room for.                  */
+use crate::buffer_capacity::point_capacity;
+
+/*  Function for shearing the mesh. The matrix is upper triangular with ones  *
+ *  on the diagonal:                                                          *
+ *                                                                            *
+ *          [ 1   shear_xy   shear_xz ]                                       *
+ *          [ 0       1      shear_yz ]                                       *
+ *          [ 0       0          1    ]                                       *
+ *                                                                            *
+ *  so that x is shifted in proportion to y and z, and y in proportion to z.  *
+ *  Shearing x and y by z gives the oblique projections, like the cavalier    *
+ *  projection, common in illustrations.                                      */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn shear_mesh(ptr: *mut f32, n_pts: u32,
+                  shear_xy: f32, shear_xz: f32, shear_yz: f32) {
+
+    /*  Avoiding going beyond the bounds of the array that was allocated.     *
+     *  The points are at most what the mesh buffer has room for.             */
+    if (n_pts as usize) > point_capacity() {
+        return;
+    }
+
+    /*  Convert the pointer into a slice.                                     */
+    let n_elements: usize = 3 * (n_pts as usize);
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, n_elements) };
+
+    /*  Loop through each point in the mesh.                                  */
+    for index in 0..n_pts {
+
+        /*  A vertex has three values, the x, y, and z coordinates. The index *
+         *  for the x value of the point is 3 times the current index.        */
+        let x_index: usize = (3 * index) as usize;
+
+        /*  The y and z indices are immediately after the x index.            */
+        let y_index: usize = x_index + 1;
+        let z_index: usize = x_index + 2;
+
+        /*  Get the initial values. z is unchanged by the shear.              */
+        let y: f32 = arr[y_index];
+        let z: f32 = arr[z_index];
+
+        /*  Apply the shear matrix and update the points. x uses the old y.   */
+        arr[x_index] += shear_xy * y + shear_xz * z;
+        arr[y_index] += shear_yz * z;
+    }
+}
+/*  End of shear_mesh.                                                        */
@@ -0,0 +1,146 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Splits every triangle of a mesh into four, adding a vertex at the     *
+ *      midpoint of each edge.                                                *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Used to find the midpoint already added for an edge.                      */
+use std::collections::HashMap;
+
+/*  Room in the mesh and index buffers.                                       */
+use crate::buffer_capacity::{index_capacity, point_capacity};
+use crate::buffer_capacity::triangle_index_capacity;
+
+/*  Function for forgetting the wireframe cached by generateIndicesCached.    */
+use crate::invalidate_index_cache::invalidate_index_cache;
+
+/*  Function for refining an arbitrary triangle mesh, such as one loaded with *
+ *  load_mesh_bytes, without evaluating the formula for the surface again.    *
+ *  Each triangle (a, b, c) is split into four, using the midpoints ab, bc,   *
+ *  and ca of its edges:                                                      *
+ *                                                                            *
+ *            (a, ab, ca), (ab, b, bc), (ca, bc, c), (ab, bc, ca)             *
+ *                                                                            *
+ *  This is the refinement step of Loop subdivision, but the points are not   *
+ *  moved afterwards, so the shape of the surface is unchanged and only the   *
+ *  resolution goes up. The winding of the original triangles is preserved.   *
+ *  An edge shared by two triangles gets a single midpoint, so the mesh stays *
+ *  connected. As in validate_winding, the number of vertices is taken from   *
+ *  the largest index. The new points are appended after the old ones, and    *
+ *  the new triangles overwrite the index array. The mesh array needs room    *
+ *  for up to 3 n_tris new points, and the index array for 12 n_tris indices. *
+ *  Returns the new number of vertices and of triangles, or zeros if there    *
+ *  are no triangles or the refined mesh does not fit in the buffers.         */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn subdivide_mesh(mesh_ptr: *mut f32, index_ptr: *mut u32,
+                      n_tris: u32) -> [u32; 2] {
+
+    /*  Each triangle becomes four, which must still fit in a u32.            */
+    let new_tris: u32 = match n_tris.checked_mul(4) {
+        Some(n) => n,
+        None => return [0, 0],
+    };
+
+    /*  Each of the new triangles is three indices. The triangles may be in   *
+     *  either index buffer, so they must fit in the smaller of the two. This *
+     *  is checked before the slice is created.                               */
+    let n_indices: usize = 3 * (new_tris as usize);
+
+    if n_indices > index_capacity().min(triangle_index_capacity()) {
+        return [0, 0];
+    }
+
+    /*  The array may hold the wireframe cached by generateIndicesCached.     */
+    invalidate_index_cache();
+
+    let indices = unsafe {
+        std::slice::from_raw_parts_mut(index_ptr, n_indices)
+    };
+
+    /*  We are not told how many vertices there are. The largest index that   *
+     *  is used tells us where to start adding the midpoints.                 */
+    let old_tris: usize = 3 * (n_tris as usize);
+
+    let n_pts: u32 = match indices[..old_tris].iter().max() {
+        Some(&largest) => largest + 1,
+        None => return [0, 0],
+    };
+
+    /*  An edge adds at most one point, and there are at most 3 per triangle. */
+    let max_pts: usize = (n_pts as usize) + old_tris;
+
+    /*  The new points are appended to the mesh, which must have room.        */
+    if max_pts > point_capacity() {
+        return [0, 0];
+    }
+    let arr = unsafe { std::slice::from_raw_parts_mut(mesh_ptr, 3 * max_pts) };
+
+    /*  The original triangles are overwritten below, so make a copy.         */
+    let triangles: Vec<u32> = indices[..old_tris].to_vec();
+
+    /*  The midpoint of each edge, with the edge labeled by its endpoints in  *
+     *  increasing order so that both triangles sharing it find it.           */
+    let mut midpoints: HashMap<(u32, u32), u32> = HashMap::new();
+
+    /*  The number of points, counting the midpoints added so far.            */
+    let mut count: u32 = n_pts;
+
+    /*  Variable for indexing over the index array being written to.          */
+    let mut index: usize = 0;
+
+    for triangle in triangles.chunks_exact(3) {
+
+        /*  The corners of the triangle, and the midpoints of its edges.      */
+        let [a, b, c]: [u32; 3] = [triangle[0], triangle[1], triangle[2]];
+        let mut mid: [u32; 3] = [0; 3];
+
+        for (n, (p, q)) in [(a, b), (b, c), (c, a)].into_iter().enumerate() {
+            let key: (u32, u32) = (p.min(q), p.max(q));
+
+            mid[n] = *midpoints.entry(key).or_insert_with(|| {
+                let p_index: usize = 3 * (p as usize);
+                let q_index: usize = 3 * (q as usize);
+                let new_index: usize = 3 * (count as usize);
+
+                for k in 0..3 {
+                    let sum: f32 = arr[p_index + k] + arr[q_index + k];
+                    arr[new_index + k] = 0.5 * sum;
+                }
+
+                count += 1;
+                return count - 1;
+            });
+        }
+        /*  End of for-loop over the edges.                                   */
+
+        let [ab, bc, ca]: [u32; 3] = mid;
+
+        /*  The three corner triangles, followed by the middle one.           */
+        let split: [u32; 12] = [a, ab, ca, ab, b, bc, ca, bc, c, ab, bc, ca];
+        indices[index..index + 12].copy_from_slice(&split);
+        index += 12;
+    }
+    /*  End of for-loop over the triangles.                                   */
+
+    return [count, new_tris];
+}
+/*  End of subdivide_mesh.                                                    */
@@ -0,0 +1,85 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Trait for surfaces that can be sampled over a rectangle, and its      *
+ *      implementation for the elliptic paraboloid.                           *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Physical width and height of the surface (projection onto the xy plane).  */
+pub use crate::{PARABOLOID_WIDTH, PARABOLOID_HEIGHT};
+
+/*  Left-most and bottom-most extremes of the surface (projected to xy plane).*/
+pub use crate::{PARABOLOID_X_START, PARABOLOID_Y_START};
+
+/*  Global for the shift in the z axis, see set_height_shift.                 */
+use crate::HEIGHT_SHIFT;
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  A surface given by a function from a rectangle in the plane to space.     *
+ *  This is used by generate_mesh_generic, which handles the buffer and the   *
+ *  bounds checks, so a new surface only needs to provide eval.               */
+pub trait Surface {
+
+    /*  Computes the point on the surface for the parameters (x, y).          */
+    fn eval(&self, x: f32, y: f32) -> [f32; 3];
+
+    /*  The interval the x parameter runs over. Defaults to the same domain   *
+     *  as the elliptic paraboloid.                                           */
+    fn x_range(&self) -> (f32, f32) {
+        return (PARABOLOID_X_START, PARABOLOID_X_START + PARABOLOID_WIDTH);
+    }
+
+    /*  The interval the y parameter runs over. Defaults to the same domain   *
+     *  as the elliptic paraboloid.                                           */
+    fn y_range(&self) -> (f32, f32) {
+        return (PARABOLOID_Y_START, PARABOLOID_Y_START + PARABOLOID_HEIGHT);
+    }
+}
+
+/*  The elliptic paraboloid z = x^2 + 2y^2, the surface drawn by              *
+ *  generate_mesh, as a Surface. The shift in the z axis is stored rather     *
+ *  than read from the HEIGHT_SHIFT global for every point, see new.          */
+pub struct EllipticParaboloid {
+    pub height_shift: f32
+}
+
+impl EllipticParaboloid {
+
+    /*  Creates the paraboloid with the current value of HEIGHT_SHIFT, the    *
+     *  same shift used by generate_mesh.                                     */
+    pub fn new() -> EllipticParaboloid {
+        return EllipticParaboloid { height_shift: *lock(&HEIGHT_SHIFT) };
+    }
+}
+
+impl Default for EllipticParaboloid {
+    fn default() -> EllipticParaboloid {
+        return EllipticParaboloid::new();
+    }
+}
+
+impl Surface for EllipticParaboloid {
+    fn eval(&self, x: f32, y: f32) -> [f32; 3] {
+        return [x, y, x * x + 2.0 * y * y + self.height_shift];
+    }
+}
+/*  End of surface.                                                           */
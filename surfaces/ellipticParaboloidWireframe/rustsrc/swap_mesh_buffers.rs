@@ -0,0 +1,43 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Swaps the front and back mesh buffers.                                *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Flag for which of the two mesh buffers is in front.                       */
+use crate::MESH_BUFFERS_SWAPPED;
+
+/*  Used for flipping the flag in a single step.                              */
+use std::sync::atomic::Ordering;
+
+/*  Function for swapping the front and back mesh buffers. A frame is drawn   *
+ *  into the buffer from get_back_mesh_buffer while the buffer returned by    *
+ *  get_mesh_buffer is rendered. Once the frame is complete, swapping makes   *
+ *  it visible, and the old front buffer becomes the new back buffer. The     *
+ *  front buffer is never written to while it is being rendered, so there is  *
+ *  no tearing. Note that JavaScript must fetch both addresses again after a  *
+ *  swap.                                                                     */
+pub fn swap_mesh_buffers() {
+
+    /*  XOR with true flips the flag. This is a single atomic operation, so   *
+     *  the two getters never see the buffers half-swapped.                   */
+    MESH_BUFFERS_SWAPPED.fetch_xor(true, Ordering::AcqRel);
+}
+/*  End of swap_mesh_buffers.                                                 */
@@ -0,0 +1,45 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Advances the transition of the coefficients of the paraboloid.        *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  State for the transition of the coefficients.                             */
+use crate::COEFF_EASING;
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  Function for advancing the transition started by set_coeff_target by dt,  *
+ *  the time since the previous frame. Negative or non-finite steps are       *
+ *  ignored. Returns true while the transition is still in progress, so the   *
+ *  caller knows whether the surface needs to be redrawn.                     */
+pub fn tick_coeffs(dt: f32) -> bool {
+
+    let mut easing = lock(&COEFF_EASING);
+
+    if dt.is_finite() && (dt > 0.0) {
+        easing.elapsed = (easing.elapsed + dt).min(easing.duration);
+        easing.update();
+    }
+
+    return easing.is_active();
+}
+/*  End of tick_coeffs.                                                       */
@@ -0,0 +1,32 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the number of indices for the triangles of the mesh.         *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Function for computing the number of elements in the triangle index       *
+ *  array, as written by generate_triangle_indices.                           */
+pub const fn triangle_index_count(nx_pts: u32, ny_pts: u32) -> u32 {
+
+    /*  There are (w - 1)(h - 1) squares in the grid. Each is split into two  *
+     *  triangles, and each triangle needs three indices.                     */
+    return 6 * (nx_pts - 1) * (ny_pts - 1);
+}
+/*  End of triangle_index_count.                                              */
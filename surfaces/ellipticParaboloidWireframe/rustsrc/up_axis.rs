@@ -0,0 +1,67 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Converts points from the Z-up convention to the chosen up axis.       *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  The axis pointing up, and the possible values.                            */
+use crate::{UP_AXIS, UP_AXIS_Y};
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  Function for checking if the up axis has been set to y by set_up_axis.    */
+pub fn is_y_up() -> bool {
+    return *lock(&UP_AXIS) == UP_AXIS_Y;
+}
+/*  End of is_y_up.                                                           */
+
+/*  Function for converting a point computed with z up to the chosen axis.    *
+ *  The generators compute z = f(x, y). For y up, (x, y, z) is sent to (x, z, *
+ *  -y), a quarter turn about the x axis. Simply swapping y and z would be a  *
+ *  reflection, turning the surface inside out and reversing the winding of   *
+ *  every triangle, which is why the sign changes.                            */
+pub fn to_up_axis(point: [f32; 3], y_up: bool) -> [f32; 3] {
+    if y_up {
+        return [point[0], point[2], -point[1]];
+    }
+
+    return point;
+}
+/*  End of to_up_axis.                                                        */
+
+/*  Function for converting a point with the chosen axis up back to z up, the *
+ *  inverse of to_up_axis. For y up, (x, y, z) is sent to (x, -z, y).         */
+pub fn from_up_axis(point: [f32; 3], y_up: bool) -> [f32; 3] {
+    if y_up {
+        return [point[0], -point[2], point[1]];
+    }
+
+    return point;
+}
+/*  End of from_up_axis.                                                      */
+
+/*  Function for converting the point starting at arr[index], computed with z *
+ *  up, to the chosen axis in place. Normals are converted the same way.      */
+pub fn convert_up_axis(arr: &mut [f32], index: usize, y_up: bool) {
+    let point: [f32; 3] = [arr[index], arr[index + 1], arr[index + 2]];
+    arr[index..index + 3].copy_from_slice(&to_up_axis(point, y_up));
+}
+/*  End of convert_up_axis.                                                   */
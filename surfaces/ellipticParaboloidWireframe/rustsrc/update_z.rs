@@ -0,0 +1,99 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Recomputes the heights of the points in the mesh, keeping x and y     *
+ *      fixed.                                                                *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Number of f32's in a single vertex, the x, y, and z coordinates.          */
+use crate::VERTEX_STRIDE;
+
+/*  Computes the length of the buffer, checking for overflow.                 */
+use crate::checked_len::checked_len;
+
+/*  Whether the up axis has been set to y by set_up_axis.                     */
+use crate::up_axis::is_y_up;
+
+/*  Status code for input that the buffers have room for.                     */
+use crate::STATUS_OK;
+
+/*  Global for the shift in the z axis, see set_height_shift.                 */
+use crate::HEIGHT_SHIFT;
+
+/*  Locks one of the globals, recovering the data if it has been poisoned.    */
+use crate::lock::lock;
+
+/*  Checks the dimensions against the current capacity of the buffers.        */
+use crate::buffer_capacity::capacity_status;
+
+/*  Function for updating the z coordinates of the mesh to z = ax^2 + by^2.   */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn update_z(ptr: *mut f32, nx_pts: u32, ny_pts: u32, a: f32, b: f32) {
+
+    /*  Avoiding writing beyond the bounds of the array that was allocated.   *
+     *  Check if the input sizes are too big.                                 */
+    if capacity_status(nx_pts, ny_pts) != STATUS_OK {
+        return;
+    }
+
+    /*  The array has the same layout as the one written by generate_mesh.    *
+     *  The length is computed with overflow checking.                        */
+    let len: usize = match checked_len(nx_pts, ny_pts, VERTEX_STRIDE) {
+        Some(len) => len,
+        None => return,
+    };
+
+    /*  Get a slice for the data.                                             */
+    let arr = unsafe { std::slice::from_raw_parts_mut(ptr, len) };
+
+    /*  Shift factor in the z axis, read once rather than for every point.    */
+    let height_shift: f32 = *lock(&HEIGHT_SHIFT);
+
+    /*  Whether the heights are stored in y rather than z, see set_up_axis.   */
+    let y_up: bool = is_y_up();
+
+    /*  Variable for indexing over the array being written to.                */
+    let mut index: usize = 0;
+
+    /*  The x and y coordinates are already in the buffer. Only the heights   *
+     *  need to be recomputed, so there is no need to track the row and       *
+     *  column.                                                               */
+    for _ in 0..(nx_pts * ny_pts) {
+
+        /*  Get the position of the point in the plane. With y up the points  *
+         *  are (x, z, -y), see to_up_axis, so the plane is the xz plane.     */
+        let x_pt: f32 = arr[index];
+        let y_pt: f32 = if y_up { -arr[index + 2] } else { arr[index + 1] };
+
+        /*  Overwrite the height, leaving the other two coordinates untouched.*/
+        let z_pt: f32 = a * x_pt * x_pt + b * y_pt * y_pt + height_shift;
+
+        if y_up {
+            arr[index + 1] = z_pt;
+        } else {
+            arr[index + 2] = z_pt;
+        }
+
+        /*  Move on to the next point in the mesh. A point needs 3 f32's.     */
+        index += 3;
+    }
+}
+/*  End of update_z.                                                          */
@@ -0,0 +1,37 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Updates the heights of the mesh using the eased coefficients.         *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Function for recomputing the heights with given coefficients.             */
+use crate::update_z::update_z;
+
+/*  The current coefficients, as eased by tick_coeffs.                        */
+use crate::get_coeffs::get_coeffs;
+
+/*  Function for updating the z coordinates of the mesh to z = a x^2 + b y^2, *
+ *  with a and b the current coefficients of the transition. Call this after  *
+ *  tick_coeffs each frame while a transition is in progress.                 */
+pub fn update_z_eased(ptr: *mut f32, nx_pts: u32, ny_pts: u32) {
+    let [a, b]: [f32; 2] = get_coeffs();
+    update_z(ptr, nx_pts, ny_pts, a, b);
+}
+/*  End of update_z_eased.                                                    */
@@ -0,0 +1,141 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Finds the fraction of triangles whose normal points down.             *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Status code for a mesh that does not fit in the buffers.                  */
+use crate::STATUS_TOO_LARGE;
+
+/*  Room in the mesh and triangle index buffers.                              */
+use crate::buffer_capacity::{point_capacity, triangle_index_capacity};
+
+/*  Functions for converting the points back to z up, see set_up_axis.        */
+use crate::up_axis::{is_y_up, from_up_axis};
+
+/*  Function for checking that the triangles are wound consistently. The      *
+ *  normal of the triangle (a, b, c) is (b - a) x (c - a). For the graph of   *
+ *  a function, with the corners listed counter-clockwise when viewed from    *
+ *  above as generate_triangle_indices does, this points up. The points are   *
+ *  converted back to z up first, so the answer is the same for either up     *
+ *  axis. Returns the fraction of triangles whose normal points down, which   *
+ *  is 0 if the winding is consistent, and 1 if it is consistent but          *
+ *  reversed. Anything in between means some triangles will be culled.        *
+ *  Rotating about the vertical axis does not change the answer. If the       *
+ *  triangles or the points they use do not fit in the buffers, -1 is         *
+ *  returned, which is STATUS_TOO_LARGE.                                      */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn validate_winding(mesh_ptr: *const f32, index_ptr: *const u32,
+                        tri_count: u32) -> f32 {
+
+    /*  Each triangle is three indices. Avoid reading beyond the end of the   *
+     *  triangle index buffer.                                                */
+    let n_indices: usize = 3 * (tri_count as usize);
+
+    if n_indices > triangle_index_capacity() {
+        return STATUS_TOO_LARGE as f32;
+    }
+
+    let indices = unsafe { std::slice::from_raw_parts(index_ptr, n_indices) };
+
+    /*  We are not told how many vertices there are. The largest index that   *
+     *  is used tells us how much of the mesh is read.                        */
+    let n_pts: usize = match indices.iter().max() {
+        Some(&largest) => (largest as usize) + 1,
+        None => return 0.0,
+    };
+
+    /*  Same for the mesh buffer, an index past the end is invalid.           */
+    if n_pts > point_capacity() {
+        return STATUS_TOO_LARGE as f32;
+    }
+
+    /*  Convert the pointer into a slice, 3 f32's per vertex.                 */
+    let arr = unsafe { std::slice::from_raw_parts(mesh_ptr, 3 * n_pts) };
+
+    /*  Whether the points were converted for y up, see set_up_axis.          */
+    let y_up: bool = is_y_up();
+
+    /*  The corner starting at arr[index], converted back to z up.            */
+    let corner = |index: usize| -> [f32; 3] {
+        let point: [f32; 3] = [arr[index], arr[index + 1], arr[index + 2]];
+        return from_up_axis(point, y_up);
+    };
+
+    /*  Counter for the triangles that face down.                             */
+    let mut count: u32 = 0;
+
+    for triangle in indices.chunks_exact(3) {
+
+        /*  The corners of the triangle, 3 f32's per vertex.                  */
+        let a: [f32; 3] = corner(3 * (triangle[0] as usize));
+        let b: [f32; 3] = corner(3 * (triangle[1] as usize));
+        let c: [f32; 3] = corner(3 * (triangle[2] as usize));
+
+        /*  Only the xy components of the edges are needed for the z          *
+         *  component of the cross product.                                   */
+        let ux: f32 = b[0] - a[0];
+        let uy: f32 = b[1] - a[1];
+        let vx: f32 = c[0] - a[0];
+        let vy: f32 = c[1] - a[1];
+
+        if ux * vy - uy * vx < 0.0 {
+            count += 1;
+        }
+    }
+
+    return (count as f32) / (tri_count as f32);
+}
+/*  End of validate_winding.                                                  */
+
+#[cfg(test)]
+mod tests {
+    use super::validate_winding;
+    use crate::set_up_axis::set_up_axis;
+    use crate::up_axis::to_up_axis;
+    use crate::{TEST_LOCK, UP_AXIS_Y, UP_AXIS_Z};
+
+    /*  One triangle facing up and one facing down. With y up the points are  *
+     *  converted as the generators do, and the answer must not change.       */
+    #[test]
+    fn same_fraction_for_either_up_axis() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let points: [[f32; 3]; 4] = [
+            [0.0, 0.0, 0.0], [1.0, 0.0, 1.0], [0.0, 1.0, 2.0], [1.0, 1.0, 3.0]
+        ];
+        let indices: [u32; 6] = [0, 1, 2, 1, 2, 3];
+
+        for (axis, y_up) in [(UP_AXIS_Z, false), (UP_AXIS_Y, true)] {
+            let mesh: Vec<f32> = points
+                .iter()
+                .flat_map(|&point| to_up_axis(point, y_up))
+                .collect();
+
+            set_up_axis(axis);
+            let fraction: f32 = validate_winding(
+                mesh.as_ptr(), indices.as_ptr(), 2
+            );
+
+            set_up_axis(UP_AXIS_Z);
+            assert_eq!(fraction, 0.5);
+        }
+    }
+}
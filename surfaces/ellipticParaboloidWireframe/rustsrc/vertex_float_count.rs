@@ -0,0 +1,67 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Computes the number of f32's in the vertex array for the mesh.        *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Number of f32's in a single vertex, the x, y, and z coordinates.          */
+use crate::VERTEX_STRIDE;
+
+/*  Function for computing the number of elements in the vertex array. This   *
+ *  is exported to JavaScript, so any input may be passed in. Zero is         *
+ *  returned for a grid with fewer than two points along an axis, and for     *
+ *  dimensions whose count does not fit in a u32.                             */
+pub const fn vertex_float_count(nx_pts: u32, ny_pts: u32) -> u32 {
+
+    /*  A grid needs at least two points along each axis, the generators      *
+     *  reject anything smaller and write nothing.                            */
+    if (nx_pts < 2) || (ny_pts < 2) {
+        return 0;
+    }
+
+    /*  There is one vertex for each point in the mesh, which is the width    *
+     *  times the height, and each vertex needs three f32's.                  */
+    let number_of_points: u32 = match nx_pts.checked_mul(ny_pts) {
+        Some(n) => n,
+        None => return 0,
+    };
+
+    return match number_of_points.checked_mul(VERTEX_STRIDE) {
+        Some(n) => n,
+        None => 0,
+    };
+}
+/*  End of vertex_float_count.                                                */
+
+#[cfg(test)]
+mod tests {
+    use super::vertex_float_count;
+
+    /*  Small grids have no vertices, and huge ones give zero instead of a    *
+     *  wrapped count.                                                        */
+    #[test]
+    fn guards_degenerate_and_huge_input() {
+        assert_eq!(vertex_float_count(3, 2), 18);
+        assert_eq!(vertex_float_count(0, 5), 0);
+        assert_eq!(vertex_float_count(5, 1), 0);
+        assert_eq!(vertex_float_count(100000, 100000), 0);
+        assert_eq!(vertex_float_count(40000, 40000), 0);
+    }
+}
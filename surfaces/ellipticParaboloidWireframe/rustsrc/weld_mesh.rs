@@ -0,0 +1,195 @@
+/******************************************************************************
+ *                                  LICENSE                                   *
+ ******************************************************************************
+ *  This file is free software: you can redistribute it and/or modify         *
+ *  it under the terms of the GNU General Public License as published by      *
+ *  the Free Software Foundation, either version 3 of the License, or         *
+ *  (at your option) any later version.                                       *
+ *                                                                            *
+ *  This file is distributed in the hope that it will be useful,              *
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of            *
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the             *
+ *  GNU General Public License for more details.                              *
+ *                                                                            *
+ *  You should have received a copy of the GNU General Public License         *
+ *  along with this file.  If not, see <https://www.gnu.org/licenses/>.       *
+ ******************************************************************************
+ *  Purpose:                                                                  *
+ *      Merges duplicate vertices of a mesh, such as those along the seam of a*
+ *      periodic surface.                                                     *
+ ******************************************************************************
+ *  Author:     Ryan Maguire                                                  *
+ *  Date:       October 15, 2026                                              *
+ ******************************************************************************/
+
+/*  Number of points and indices the buffers currently have room for.         */
+use crate::buffer_capacity::{index_capacity, point_capacity};
+use crate::buffer_capacity::triangle_index_capacity;
+
+/*  Used to look up the points that have been kept, by their cell.            */
+use std::collections::HashMap;
+
+/*  Function for forgetting the wireframe cached by generateIndicesCached.    */
+use crate::invalidate_index_cache::invalidate_index_cache;
+
+/*  Label for the cube of side eps containing a point.                        */
+type Cell = (i32, i32, i32);
+
+/*  Computes the cell containing the point (x, y, z). Every point within eps  *
+ *  of it lies in this cell or one of the 26 around it.                       */
+fn cell_of(x: f32, y: f32, z: f32, rcpr_eps: f32) -> Cell {
+    return (
+        (x * rcpr_eps).floor() as i32,
+        (y * rcpr_eps).floor() as i32,
+        (z * rcpr_eps).floor() as i32
+    );
+}
+/*  End of cell_of.                                                           */
+
+/*  Function for welding the vertices of a mesh. Surfaces like the sphere and *
+ *  torus repeat the points along their seams, and the normals computed from  *
+ *  the two copies do not agree, leaving a visible crease in smooth shading.  *
+ *  Every point within eps of a point that came before it is merged into the  *
+ *  earlier one. The points that are kept are moved to the front of the mesh, *
+ *  in their original order, and the n_indices entries of the index array are *
+ *  rewritten to refer to them. Lines joining two merged points, like those   *
+ *  around the poles of the sphere, become zero length but are left in place  *
+ *  so that the index count does not change. Positions are hashed by the cube *
+ *  of side eps they fall in, so only nearby points are compared. Returns the *
+ *  new number of vertices, or zero if the input is invalid.                  */
+#[allow(clippy::not_unsafe_ptr_arg_deref,
+        reason = "the pointers are buffers in wasm memory from JavaScript")]
+pub fn weld_mesh(mesh_ptr: *mut f32, index_ptr: *mut u32,
+                 n_pts: u32, n_indices: u32, eps: f32) -> u32 {
+
+    /*  The cells need a positive, finite size.                               */
+    if !eps.is_finite() || (eps <= 0.0) {
+        return 0;
+    }
+
+    /*  Avoiding reading beyond the bounds of the arrays that were allocated. *
+     *  The indices may be in either index buffer, so check against the       *
+     *  smaller of the two.                                                   */
+    if (n_pts as usize) > point_capacity() {
+        return 0;
+    }
+
+    if (n_indices as usize) > index_capacity().min(triangle_index_capacity()) {
+        return 0;
+    }
+
+    /*  The array may hold the wireframe cached by generateIndicesCached.     */
+    invalidate_index_cache();
+
+    /*  Convert the pointers into slices. Each point has three f32's.         */
+    let n_elements: usize = (3 * n_pts) as usize;
+    let mesh = unsafe { std::slice::from_raw_parts_mut(mesh_ptr, n_elements) };
+    let indices = unsafe {
+        std::slice::from_raw_parts_mut(index_ptr, n_indices as usize)
+    };
+
+    /*  Multiplying is cheaper than dividing, compute the reciprocal once.    */
+    let rcpr_eps: f32 = 1.0 / eps;
+    let eps_sq: f32 = eps * eps;
+
+    /*  The new index of every old point, and the points kept in each cell.   */
+    let mut remap: Vec<u32> = vec![0; n_pts as usize];
+    let mut cells: HashMap<Cell, Vec<u32>> = HashMap::new();
+
+    /*  The number of points kept so far.                                     */
+    let mut count: u32 = 0;
+
+    for point in 0..n_pts {
+
+        /*  The current point and its cell.                                   */
+        let old: usize = 3 * (point as usize);
+        let x: f32 = mesh[old];
+        let y: f32 = mesh[old + 1];
+        let z: f32 = mesh[old + 2];
+        let (cx, cy, cz): Cell = cell_of(x, y, z, rcpr_eps);
+
+        /*  Search the surrounding cells for a point that was already kept.   */
+        let mut survivor: Option<u32> = None;
+
+        'search: for dx in -1..=1 {
+            for dy in -1..=1 {
+                for dz in -1..=1 {
+                    /*  A tiny eps or a far away point saturates the cast     *
+                     *  in cell_of, so the offset must saturate too.          */
+                    let key: Cell = (
+                        cx.saturating_add(dx),
+                        cy.saturating_add(dy),
+                        cz.saturating_add(dz)
+                    );
+
+                    let Some(kept) = cells.get(&key) else {
+                        continue;
+                    };
+
+                    for &other in kept {
+                        let new: usize = 3 * (other as usize);
+                        let ex: f32 = mesh[new] - x;
+                        let ey: f32 = mesh[new + 1] - y;
+                        let ez: f32 = mesh[new + 2] - z;
+
+                        if ex * ex + ey * ey + ez * ez <= eps_sq {
+                            survivor = Some(other);
+                            break 'search;
+                        }
+                    }
+                }
+            }
+        }
+        /*  End of search over the neighboring cells.                         */
+
+        if let Some(other) = survivor {
+            remap[point as usize] = other;
+            continue;
+        }
+
+        /*  No match, keep the point. Since count <= point, moving it forward *
+         *  never overwrites a point that has not been visited yet.           */
+        let new: usize = 3 * (count as usize);
+        mesh[new] = x;
+        mesh[new + 1] = y;
+        mesh[new + 2] = z;
+
+        remap[point as usize] = count;
+        cells.entry((cx, cy, cz)).or_default().push(count);
+        count += 1;
+    }
+    /*  End of for-loop over the points.                                      */
+
+    /*  Point the indices at the kept points. Entries that are out of range   *
+     *  do not refer to this mesh, leave them alone.                          */
+    for index in indices.iter_mut() {
+        if *index < n_pts {
+            *index = remap[*index as usize];
+        }
+    }
+
+    return count;
+}
+/*  End of weld_mesh.                                                         */
+
+#[cfg(test)]
+mod tests {
+    use super::weld_mesh;
+
+    /*  With eps far below the spacing of the coordinates, the cells saturate *
+     *  at the edge of the i32 range. Looking up their neighbors must not     *
+     *  overflow, and the distinct points must all be kept.                   */
+    #[test]
+    fn tiny_eps_does_not_overflow() {
+        let mut mesh: [f32; 9] = [1.0E30, 0.0, 0.0, -1.0E30, 0.0, 0.0,
+                                  1.0E30, 1.0, 0.0];
+        let mut indices: [u32; 2] = [0, 2];
+
+        let count: u32 = weld_mesh(
+            mesh.as_mut_ptr(), indices.as_mut_ptr(), 3, 2, 1.0E-30
+        );
+
+        assert_eq!(count, 3);
+        assert_eq!(indices, [0, 2]);
+    }
+}